@@ -0,0 +1,110 @@
+use core::ops::{AddAssign, SubAssign};
+
+use crate::FixedDec;
+use crate::number::Number;
+
+/// An accumulator of FixedDec values, summing in the wider backing type `T::Wide`
+///
+/// The running sum can go beyond the range of T, as long as the final sum fits in T,
+/// which is useful to aggregate many values without intermediate overflow.
+///
+/// ```
+/// use fixeddec::{FixedDec, FixedDecAcc};
+/// let mut acc = FixedDecAcc::<i32, 2>::new();
+/// acc += FixedDec::MAX;
+/// acc += FixedDec::MAX;
+/// acc -= FixedDec::MAX;
+/// assert_eq!(acc.finish(), Some(FixedDec::MAX));
+/// ```
+///
+/// The addition and subtraction operators panic if the running sum overflow `T::Wide`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FixedDecAcc<T: Number, const P: u32> {
+    sum: T::Wide,
+}
+
+impl<T: Number, const P: u32> FixedDecAcc<T, P> {
+    /// Create a new accumulator with a sum of zero
+    pub fn new() -> Self {
+        Self {
+            sum: <T::Wide as Number>::ZERO,
+        }
+    }
+
+    /// Return the running sum, at precision P in the wider backing type
+    pub fn sum(&self) -> FixedDec<T::Wide, P> {
+        FixedDec(self.sum)
+    }
+
+    /// Return the sum, if it fits in the backing type T
+    pub fn finish(self) -> Option<FixedDec<T, P>> {
+        T::try_from_wide(self.sum).map(FixedDec)
+    }
+}
+
+impl<T: Number, const P: u32> Default for FixedDecAcc<T, P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Number, const P: u32> AddAssign<FixedDec<T, P>> for FixedDecAcc<T, P> {
+    fn add_assign(&mut self, rhs: FixedDec<T, P>) {
+        self.sum = self
+            .sum
+            .checked_add(rhs.0.to_wide())
+            .expect("accumulator overflow");
+    }
+}
+
+impl<T: Number, const P: u32> SubAssign<FixedDec<T, P>> for FixedDecAcc<T, P> {
+    fn sub_assign(&mut self, rhs: FixedDec<T, P>) {
+        self.sum = self
+            .sum
+            .checked_sub(rhs.0.to_wide())
+            .expect("accumulator overflow");
+    }
+}
+
+impl<T: Number, const P: u32> Extend<FixedDec<T, P>> for FixedDecAcc<T, P> {
+    fn extend<I: IntoIterator<Item = FixedDec<T, P>>>(&mut self, iter: I) {
+        for v in iter {
+            *self += v;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accumulate() {
+        let v = FixedDec::<i32, 2>::new(1_000_000_000);
+        let mut acc = FixedDecAcc::new();
+        for _ in 0..1_000 {
+            acc += v;
+        }
+        assert_eq!(acc.sum(), FixedDec::<i64, 2>::new(1_000_000_000_000));
+        assert_eq!(acc.finish(), None);
+
+        acc.extend(core::iter::repeat_n(FixedDec::new(-1_000_000_000), 999));
+        assert_eq!(acc.finish(), Some(v));
+
+        acc -= v;
+        acc -= v;
+        assert_eq!(acc.finish(), Some(FixedDec::new(-1_000_000_000)));
+        assert_eq!(
+            FixedDecAcc::<u8, 1>::default().finish(),
+            Some(FixedDec::new(0))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "accumulator overflow")]
+    fn wide_overflow() {
+        let mut acc = FixedDecAcc::<u128, 2>::new();
+        acc += FixedDec::MAX;
+        acc += FixedDec::MAX;
+    }
+}
@@ -1,5 +1,6 @@
 use crate::FixedDec;
 
+#[allow(clippy::inconsistent_digit_grouping)]
 const PI_128DIGITS: u128 = 3_141_592_653_589_793_238_462_643_383_279_502_884_19;
 const PI_64DIGITS: u64 = 3_141_592_653_589_793_238;
 const PI_32DIGITS: u32 = 3_141_592_653;
@@ -8,6 +9,57 @@ pub const PI128: FixedDec<u128, 38> = FixedDec::new(PI_128DIGITS);
 pub const PI64: FixedDec<u64, 18> = FixedDec::new(PI_64DIGITS);
 pub const PI32: FixedDec<u32, 9> = FixedDec::new(PI_32DIGITS);
 
+macro_rules! pi_at {
+    ($fn:ident, $ty:ty, $digits:ident, $max:literal, [$($name:ident = $p:literal),*]) => {
+        #[doc = concat!("PI truncated at precision P, for P up to ", $max)]
+        ///
+        #[doc = concat!("The digits of PI are only known up to ", $max, ", so a bigger P is a compile error,")]
+        #[doc = concat!("even when it is a valid precision for ", stringify!($ty), ":")]
+        ///
+        /// ```compile_fail
+        #[doc = concat!("let _ = fixeddec::constants::", stringify!($fn), "::<{ ", $max, " + 1 }>();")]
+        /// ```
+        pub const fn $fn<const P: u32>() -> FixedDec<$ty, P> {
+            const { assert!(P <= $max, "precision above the known digits of PI") };
+            FixedDec::new($digits / (10 as $ty).pow($max - P))
+        }
+
+        $(
+            #[doc = concat!("PI truncated at precision ", $p)]
+            pub const $name: FixedDec<$ty, $p> = $fn::<$p>();
+        )*
+    };
+}
+
+pi_at!(
+    pi32,
+    u32,
+    PI_32DIGITS,
+    9,
+    [PI32_2 = 2, PI32_4 = 4, PI32_6 = 6]
+);
+pi_at!(
+    pi64,
+    u64,
+    PI_64DIGITS,
+    18,
+    [PI64_2 = 2, PI64_4 = 4, PI64_6 = 6, PI64_9 = 9, PI64_12 = 12]
+);
+pi_at!(
+    pi128,
+    u128,
+    PI_128DIGITS,
+    38,
+    [
+        PI128_2 = 2,
+        PI128_4 = 4,
+        PI128_6 = 6,
+        PI128_9 = 9,
+        PI128_12 = 12,
+        PI128_18 = 18
+    ]
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -29,4 +81,20 @@ mod tests {
         assert_eq!(PI64.set_precision::<3>(), Some(FixedDec::new(3141)));
         assert_eq!(PI64.set_precision::<4>(), Some(FixedDec::new(31415)));
     }
+
+    #[test]
+    fn pies_at() {
+        assert_eq!(alloc::format!("{}", PI64_6), "3.141592");
+        assert_eq!(alloc::format!("{}", PI32_2), "3.14");
+        assert_eq!(alloc::format!("{}", PI128_18), "3.141592653589793238");
+
+        assert_eq!(pi32::<9>(), PI32);
+        assert_eq!(pi64::<18>(), PI64);
+        assert_eq!(pi128::<38>(), PI128);
+        assert_eq!(pi64::<0>(), FixedDec::new(3));
+        assert_eq!(Some(PI64_6), PI64.set_precision::<6>());
+        assert_eq!(Some(PI64_12), PI64.set_precision::<12>());
+        assert_eq!(Some(PI128_9), PI128.set_precision::<9>());
+        assert_eq!(Some(PI32_4), PI32.set_precision::<4>());
+    }
 }
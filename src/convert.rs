@@ -0,0 +1,208 @@
+use core::fmt;
+
+use crate::FixedDec;
+use crate::number::Number;
+
+/// Error returned when a conversion from or to a FixedDec doesn't fit in the target type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TryFromFixedDecError(pub(crate) ());
+
+impl fmt::Display for TryFromFixedDecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("out of range decimal conversion attempted")
+    }
+}
+
+impl core::error::Error for TryFromFixedDecError {}
+
+// lossless conversions between backing types, for the pairs where the builtin
+// integer types implement `From`
+macro_rules! widening_from_impl {
+    ($from:ty => $($to:ty),*) => {
+        $(
+            /// Convert to a wider backing type, which can represent all the values at the same precision
+            impl<const P: u32> From<FixedDec<$from, P>> for FixedDec<$to, P> {
+                fn from(value: FixedDec<$from, P>) -> Self {
+                    FixedDec(<$to>::from(value.0))
+                }
+            }
+        )*
+    };
+}
+
+widening_from_impl!(u8 => u16, u32, u64, u128, i16, i32, i64, i128);
+widening_from_impl!(u16 => u32, u64, u128, i32, i64, i128);
+widening_from_impl!(u32 => u64, u128, i64, i128);
+widening_from_impl!(u64 => u128, i128);
+widening_from_impl!(i8 => i16, i32, i64, i128);
+widening_from_impl!(i16 => i32, i64, i128);
+widening_from_impl!(i32 => i64, i128);
+widening_from_impl!(i64 => i128);
+
+// conversions between backing types which can fail, for the pairs without `From`
+macro_rules! narrowing_try_from_impl {
+    ($from:ty => $($to:ty),*) => {
+        $(
+            /// Convert to a backing type which can't represent all the values, failing if the
+            /// value (or the precision P) is out of the range of the target type
+            impl<const P: u32> TryFrom<FixedDec<$from, P>> for FixedDec<$to, P> {
+                type Error = TryFromFixedDecError;
+
+                fn try_from(value: FixedDec<$from, P>) -> Result<Self, Self::Error> {
+                    <$to>::try_from(value.0)
+                        .ok()
+                        .and_then(FixedDec::new_checked)
+                        .ok_or(TryFromFixedDecError(()))
+                }
+            }
+        )*
+    };
+}
+
+narrowing_try_from_impl!(u8 => i8);
+narrowing_try_from_impl!(u16 => u8, i8, i16);
+narrowing_try_from_impl!(u32 => u8, u16, i8, i16, i32);
+narrowing_try_from_impl!(u64 => u8, u16, u32, i8, i16, i32, i64);
+narrowing_try_from_impl!(u128 => u8, u16, u32, u64, i8, i16, i32, i64, i128);
+narrowing_try_from_impl!(i8 => u8, u16, u32, u64, u128);
+narrowing_try_from_impl!(i16 => u8, u16, u32, u64, u128, i8);
+narrowing_try_from_impl!(i32 => u8, u16, u32, u64, u128, i8, i16);
+narrowing_try_from_impl!(i64 => u8, u16, u32, u64, u128, i8, i16, i32);
+narrowing_try_from_impl!(i128 => u8, u16, u32, u64, u128, i8, i16, i32, i64);
+
+/// Convert to the nearest f64, see [`FixedDec::to_f64`]
+///
+/// This conversion is lossy: most decimal fractions (e.g. `0.1`) have no exact binary
+/// representation, and the raw values bigger than 2^53 lose their lower digits.
+///
+/// ```
+/// use fixeddec::FixedDec;
+/// let x: f64 = FixedDec::<i32, 2>::new(-125).into();
+/// assert_eq!(x, -1.25);
+/// ```
+impl<T: Number, const P: u32> From<FixedDec<T, P>> for f64 {
+    fn from(value: FixedDec<T, P>) -> Self {
+        value.to_f64()
+    }
+}
+
+/// Convert to a f32, through the nearest f64
+///
+/// This conversion is lossy, even more than the conversion to f64: a f32 has only 24 bits
+/// of mantissa (about 7 decimal digits), and the big values of the 128 bits types are out
+/// of its range and become infinite.
+///
+/// ```
+/// use fixeddec::FixedDec;
+/// let x: f32 = FixedDec::<u32, 2>::new(125).into();
+/// assert_eq!(x, 1.25);
+/// ```
+impl<T: Number, const P: u32> From<FixedDec<T, P>> for f32 {
+    fn from(value: FixedDec<T, P>) -> Self {
+        value.to_f64() as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn widening() {
+        let f = FixedDec::<u8, 2>::MAX;
+        assert_eq!(FixedDec::<u16, 2>::from(f), FixedDec::new(255));
+        assert_eq!(FixedDec::<u128, 2>::from(f), FixedDec::new(255));
+        assert_eq!(FixedDec::<i16, 2>::from(f), FixedDec::new(255));
+
+        let f = FixedDec::<u32, 4>::new(u32::MAX);
+        let w: FixedDec<u64, 4> = f.into();
+        assert_eq!(w.value(), u64::from(u32::MAX));
+        let w: FixedDec<i64, 4> = f.into();
+        assert_eq!(w.value(), i64::from(u32::MAX));
+
+        let f = FixedDec::<i8, 1>::MIN;
+        assert_eq!(FixedDec::<i32, 1>::from(f), FixedDec::new(-128));
+        let f = FixedDec::<i64, 6>::MIN;
+        assert_eq!(FixedDec::<i128, 6>::from(f).value(), i128::from(i64::MIN));
+
+        // the same value is represented
+        let f = FixedDec::<u16, 3>::from_str("12.345").unwrap();
+        assert_eq!(
+            FixedDec::<i32, 3>::from(f),
+            FixedDec::from_str("12.345").unwrap()
+        );
+        assert_eq!(FixedDec::<u64, 3>::from(f).to_string(), f.to_string());
+    }
+
+    #[test]
+    fn float() {
+        let f = FixedDec::<i64, 3>::new(-1_500);
+        assert_eq!(f64::from(f), -1.5);
+        assert_eq!(f32::from(f), -1.5);
+        let x: f64 = FixedDec::<u8, 1>::MAX.into();
+        assert_eq!(x, 25.5);
+
+        // lossy: 0.1 is not exactly representable, and f32 rounds the low digits
+        assert_eq!(f64::from(FixedDec::<u32, 1>::new(1)), 0.1);
+        assert_eq!(f32::from(FixedDec::<u32, 0>::new(16_777_217)), 16_777_216.0);
+        assert_eq!(f32::from(FixedDec::<u128, 0>::MAX), f32::INFINITY);
+
+        fn total<F: Into<f64>>(values: [F; 2]) -> f64 {
+            values.into_iter().map(Into::into).sum()
+        }
+        assert_eq!(
+            total([FixedDec::<u32, 2>::new(125), FixedDec::new(75)]),
+            2.0
+        );
+    }
+
+    #[test]
+    fn narrowing() {
+        let f = FixedDec::<u64, 2>::new(1234);
+        assert_eq!(FixedDec::<u32, 2>::try_from(f), Ok(FixedDec::new(1234)));
+        assert_eq!(FixedDec::<i16, 2>::try_from(f), Ok(FixedDec::new(1234)));
+        assert_eq!(
+            FixedDec::<u8, 2>::try_from(f),
+            Err(TryFromFixedDecError(()))
+        );
+
+        let f = FixedDec::<u64, 2>::new(u64::from(u32::MAX) + 1);
+        assert!(FixedDec::<u32, 2>::try_from(f).is_err());
+        let f = FixedDec::<u64, 2>::new(u64::from(u32::MAX));
+        assert_eq!(FixedDec::<u32, 2>::try_from(f), Ok(FixedDec::MAX));
+
+        // sign changes
+        let n = FixedDec::<i32, 1>::new(-1);
+        assert!(FixedDec::<u32, 1>::try_from(n).is_err());
+        assert!(FixedDec::<u128, 1>::try_from(n).is_err());
+        assert_eq!(FixedDec::<i8, 1>::try_from(n), Ok(FixedDec::new(-1)));
+        assert!(FixedDec::<i8, 2>::try_from(FixedDec::<u8, 2>::MAX).is_err());
+        assert!(FixedDec::<i128, 0>::try_from(FixedDec::<u128, 0>::MAX).is_err());
+
+        // the precision need to be valid for the target type
+        let f = FixedDec::<u64, 5>::new(1);
+        assert!(FixedDec::<u8, 5>::try_from(f).is_err());
+        assert!(FixedDec::<u16, 5>::try_from(f).is_err());
+        assert!(FixedDec::<u32, 5>::try_from(f).is_ok());
+
+        // usable with the question mark operator
+        fn total(
+            a: FixedDec<u64, 2>,
+            b: FixedDec<u64, 2>,
+        ) -> Result<FixedDec<u32, 2>, TryFromFixedDecError> {
+            let a = FixedDec::<u32, 2>::try_from(a)?;
+            let b = FixedDec::try_from(b)?;
+            Ok(a + b)
+        }
+        assert_eq!(
+            total(FixedDec::new(100), FixedDec::new(250)),
+            Ok(FixedDec::new(350))
+        );
+        assert!(total(FixedDec::MAX, FixedDec::new(250)).is_err());
+        assert_eq!(
+            TryFromFixedDecError(()).to_string(),
+            "out of range decimal conversion attempted"
+        );
+    }
+}
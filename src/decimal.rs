@@ -0,0 +1,127 @@
+//! Conversions with [`rust_decimal::Decimal`], with the `rust_decimal` feature
+//!
+//! A Decimal has a 96 bits mantissa and a scale up to 28 digits. When converting
+//! a Decimal with a scale bigger than P, the dropped digits are rounded half to even
+//! (banker's rounding), which is also the default rounding of rust_decimal.
+//!
+//! ```
+//! use fixeddec::FixedDec;
+//! use rust_decimal::Decimal;
+//!
+//! let d = Decimal::new(12345, 3); // 12.345
+//! let f = FixedDec::<i64, 2>::try_from(d).unwrap();
+//! assert_eq!(f, FixedDec::new(1234));
+//! assert_eq!(Decimal::from(f), Decimal::new(1234, 2));
+//! ```
+use rust_decimal::Decimal;
+
+use crate::FixedDec;
+use crate::convert::TryFromFixedDecError;
+use crate::number::{Number, ten_power};
+use crate::rounding::{self, RoundingMode};
+
+/// Convert a Decimal to a FixedDec, rounding half to even the digits after P
+///
+/// Returns an error if the value doesn't fit in T at precision P
+impl<T, const P: u32> TryFrom<Decimal> for FixedDec<T, P>
+where
+    T: Number + TryFrom<i128>,
+{
+    type Error = TryFromFixedDecError;
+
+    fn try_from(d: Decimal) -> Result<Self, Self::Error> {
+        let (mantissa, scale) = (d.mantissa(), d.scale());
+        let raw = if scale <= P {
+            ten_power::<i128>(P - scale).and_then(|prec| mantissa.checked_mul(prec))
+        } else {
+            // the scale of a Decimal is at most 28, so the power always fits in i128
+            ten_power::<i128>(scale - P)
+                .and_then(|prec| rounding::div_round(mantissa, prec, RoundingMode::HalfEven))
+        };
+        raw.and_then(|raw| T::try_from(raw).ok())
+            .and_then(FixedDec::new_checked)
+            .ok_or(TryFromFixedDecError(()))
+    }
+}
+
+macro_rules! decimal_from_impl {
+    ($($ty:ty),*) => {
+        $(
+            /// Convert a FixedDec to a Decimal, which is always exact for this type
+            impl<const P: u32> From<FixedDec<$ty, P>> for Decimal {
+                fn from(f: FixedDec<$ty, P>) -> Self {
+                    // at most 64 bits mantissa and 19 digits of scale
+                    Decimal::from_i128_with_scale(i128::from(f.value()), P)
+                }
+            }
+        )*
+    };
+}
+
+decimal_from_impl!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+macro_rules! decimal_try_from_impl {
+    ($($ty:ty),*) => {
+        $(
+            /// Convert a FixedDec to a Decimal, which is exact when it fits
+            ///
+            /// Returns an error if the raw value doesn't fit in 96 bits, or P is bigger than 28
+            impl<const P: u32> TryFrom<FixedDec<$ty, P>> for Decimal {
+                type Error = TryFromFixedDecError;
+
+                fn try_from(f: FixedDec<$ty, P>) -> Result<Self, Self::Error> {
+                    i128::try_from(f.value())
+                        .ok()
+                        .and_then(|raw| Decimal::try_from_i128_with_scale(raw, P).ok())
+                        .ok_or(TryFromFixedDecError(()))
+                }
+            }
+        )*
+    };
+}
+
+decimal_try_from_impl!(u128, i128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for (mantissa, scale, raw) in [(0, 0, 0), (12345, 2, 12345), (-5, 2, -5), (-7, 0, -700)] {
+            let d = Decimal::new(mantissa, scale);
+            let f = FixedDec::<i64, 2>::try_from(d).unwrap();
+            assert_eq!(f, FixedDec::new(raw));
+            assert_eq!(Decimal::from(f), d);
+        }
+
+        let f = FixedDec::<u64, 4>::MAX;
+        assert_eq!(FixedDec::try_from(Decimal::from(f)), Ok(f));
+        let f = FixedDec::<i128, 10>::new(-123_456_789_012_345_678_901);
+        assert_eq!(FixedDec::try_from(Decimal::try_from(f).unwrap()), Ok(f));
+    }
+
+    #[test]
+    fn scaling() {
+        let d = Decimal::new(15, 1); // 1.5
+        assert_eq!(FixedDec::<u32, 3>::try_from(d), Ok(FixedDec::new(1500)));
+
+        // rounding half to even
+        let f = |m, s| FixedDec::<i32, 1>::try_from(Decimal::new(m, s));
+        assert_eq!(f(125, 2), Ok(FixedDec::new(12)));
+        assert_eq!(f(135, 2), Ok(FixedDec::new(14)));
+        assert_eq!(f(1251, 3), Ok(FixedDec::new(13)));
+        assert_eq!(f(-125, 2), Ok(FixedDec::new(-12)));
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert!(FixedDec::<u8, 1>::try_from(Decimal::new(256, 1)).is_err());
+        assert!(FixedDec::<u32, 2>::try_from(Decimal::new(-1, 2)).is_err());
+        assert!(FixedDec::<u8, 3>::try_from(Decimal::new(1, 3)).is_err());
+        assert!(FixedDec::<u64, 19>::try_from(Decimal::MAX).is_err());
+
+        assert!(Decimal::try_from(FixedDec::<u128, 0>::MAX).is_err());
+        assert!(Decimal::try_from(FixedDec::<i128, 30>::new(1)).is_err());
+    }
+}
@@ -0,0 +1,143 @@
+use crate::FixedDec;
+use crate::number::{Number, ten_power};
+
+/// A integral number with a precision of fractional digits chosen at runtime
+///
+/// This is the runtime equivalent of [`FixedDec`], storing the precision along the value,
+/// which is useful when the precision comes from a configuration (e.g. a currency table).
+///
+/// Arithmetic between two values is only possible when they have the same precision,
+/// otherwise None is returned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DynFixedDec<T: Number> {
+    value: T,
+    precision: u32,
+}
+
+impl<T: Number> DynFixedDec<T> {
+    /// Create a new DynFixedDec using the backing value already at the given precision
+    ///
+    /// ```
+    /// use fixeddec::DynFixedDec;
+    /// let f = DynFixedDec::<u32>::new(1_234, 3).unwrap();
+    /// assert_eq!(f.value(), 1_234);
+    /// assert_eq!(f.precision(), 3);
+    /// ```
+    ///
+    /// If the precision is not supported by the backing type, returns None
+    pub fn new(value: T, precision: u32) -> Option<Self> {
+        ten_power::<T>(precision).map(|_| Self { value, precision })
+    }
+
+    /// Return the content value at the precision of this decimal
+    pub const fn value(self) -> T {
+        self.value
+    }
+
+    /// Return the number of fractional digits of this decimal
+    pub const fn precision(self) -> u32 {
+        self.precision
+    }
+
+    /// Add two elements with checked result
+    ///
+    /// If the precisions doesn't match, or the addition result doesn't fits in the type T,
+    /// then None is returned
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.same_precision(rhs)?;
+        self.value
+            .checked_add(rhs.value)
+            .map(|value| Self { value, ..self })
+    }
+
+    /// Subtract two elements with checked result
+    ///
+    /// If the precisions doesn't match, or the subtraction result doesn't fits in the type T,
+    /// then None is returned
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.same_precision(rhs)?;
+        self.value
+            .checked_sub(rhs.value)
+            .map(|value| Self { value, ..self })
+    }
+
+    /// Multiplication (Scaling) with checked result
+    pub fn checked_mul(self, rhs: T) -> Option<Self> {
+        self.value
+            .checked_mul(rhs)
+            .map(|value| Self { value, ..self })
+    }
+
+    /// Division (Inverse Scaling) with checked result
+    pub fn checked_div(self, rhs: T) -> Option<Self> {
+        self.value
+            .checked_div(rhs)
+            .map(|value| Self { value, ..self })
+    }
+
+    /// Convert to a FixedDec of the same precision
+    ///
+    /// ```
+    /// use fixeddec::{DynFixedDec, FixedDec};
+    /// let f = DynFixedDec::<u32>::new(1_234, 3).unwrap();
+    /// assert_eq!(f.to_fixed::<3>(), Some(FixedDec::new(1_234)));
+    /// assert_eq!(f.to_fixed::<2>(), None);
+    /// ```
+    ///
+    /// If the precision is not P, returns None
+    pub fn to_fixed<const P: u32>(self) -> Option<FixedDec<T, P>> {
+        if self.precision == P {
+            Some(FixedDec::new(self.value))
+        } else {
+            None
+        }
+    }
+
+    fn same_precision(self, rhs: Self) -> Option<()> {
+        (self.precision == rhs.precision).then_some(())
+    }
+}
+
+impl<T: Number, const P: u32> From<FixedDec<T, P>> for DynFixedDec<T> {
+    fn from(value: FixedDec<T, P>) -> Self {
+        Self {
+            value: value.value(),
+            precision: P,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dyn_arithmetic() {
+        let a = DynFixedDec::<i32>::new(1250, 2).unwrap();
+        let b = DynFixedDec::<i32>::new(-300, 2).unwrap();
+        let c = DynFixedDec::<i32>::new(1250, 3).unwrap();
+
+        assert_eq!(a.checked_add(b), DynFixedDec::new(950, 2));
+        assert_eq!(a.checked_sub(b), DynFixedDec::new(1550, 2));
+        assert_eq!(a.checked_add(c), None);
+        assert_eq!(a.checked_sub(c), None);
+        assert_eq!(a.checked_mul(3), DynFixedDec::new(3750, 2));
+        assert_eq!(a.checked_div(2), DynFixedDec::new(625, 2));
+
+        let max = DynFixedDec::<i32>::new(i32::MAX, 2).unwrap();
+        assert_eq!(max.checked_add(a), None);
+        assert_eq!(b.checked_sub(max).and_then(|x| x.checked_sub(a)), None);
+    }
+
+    #[test]
+    fn dyn_conversion() {
+        assert_eq!(DynFixedDec::<u8>::new(1, 2).map(|x| x.precision()), Some(2));
+        assert_eq!(DynFixedDec::<u8>::new(1, 3), None);
+
+        let f = FixedDec::<u64, 4>::new(12345);
+        let d = DynFixedDec::from(f);
+        assert_eq!((d.value(), d.precision()), (12345, 4));
+        assert_eq!(d.to_fixed::<4>(), Some(f));
+        assert_eq!(d.to_fixed::<2>(), None);
+    }
+}
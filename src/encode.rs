@@ -0,0 +1,96 @@
+use alloc::vec::Vec;
+
+use crate::FixedDec;
+
+// the encoding uses the byte representation of the builtin types, so it's
+// implemented for each builtin type.
+macro_rules! encode_impl {
+    ($($ty:ty),*) => {
+        $(
+            impl<const P: u32> FixedDec<$ty, P> {
+                /// Size in bytes of the encoding, the precision byte followed by the raw value
+                pub const ENCODED_LEN: usize = 1 + core::mem::size_of::<$ty>();
+
+                /// Encode the value in a self-describing binary format
+                ///
+                /// The encoding is 1 byte for the precision P, followed by the raw value
+                /// in little endian, and is decoded with [`Self::decode`].
+                ///
+                /// ```
+                /// use fixeddec::FixedDec;
+                #[doc = concat!("let f = FixedDec::<", stringify!($ty), ", 2>::new(100);")]
+                /// let bytes = f.encode();
+                /// assert_eq!(bytes[0], 2);
+                #[doc = concat!("assert_eq!(FixedDec::<", stringify!($ty), ", 2>::decode(&bytes), Some(f));")]
+                /// ```
+                pub fn encode(self) -> Vec<u8> {
+                    let mut bytes = Vec::with_capacity(Self::ENCODED_LEN);
+                    // the precision of the builtin types is at most 38
+                    bytes.push(P as u8);
+                    bytes.extend_from_slice(&self.0.to_le_bytes());
+                    bytes
+                }
+
+                /// Decode a value encoded by [`Self::encode`]
+                ///
+                /// Returns None if the length of the bytes isn't [`Self::ENCODED_LEN`],
+                /// or if the encoded precision isn't P.
+                pub fn decode(bytes: &[u8]) -> Option<Self> {
+                    let (&precision, raw) = bytes.split_first()?;
+                    if u32::from(precision) != P {
+                        return None;
+                    }
+                    let raw = raw.try_into().ok()?;
+                    Self::new_checked(<$ty>::from_le_bytes(raw))
+                }
+            }
+        )*
+    };
+}
+
+encode_impl!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for v in [0, 1, -1, 12345, i64::MIN, i64::MAX] {
+            let f = FixedDec::<i64, 4>::new(v);
+            let bytes = f.encode();
+            assert_eq!(bytes.len(), FixedDec::<i64, 4>::ENCODED_LEN);
+            assert_eq!(bytes.len(), 9);
+            assert_eq!(FixedDec::<i64, 4>::decode(&bytes), Some(f));
+        }
+        let f = FixedDec::<u128, 38>::MAX;
+        assert_eq!(FixedDec::<u128, 38>::decode(&f.encode()), Some(f));
+        let f = FixedDec::<u8, 0>::new(7);
+        assert_eq!(f.encode(), [0, 7]);
+
+        let f = FixedDec::<u32, 3>::new(0x01020304);
+        assert_eq!(f.encode(), [3, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn precision_mismatch() {
+        let bytes = FixedDec::<u32, 3>::new(1234).encode();
+        assert_eq!(FixedDec::<u32, 2>::decode(&bytes), None);
+        assert_eq!(FixedDec::<u32, 4>::decode(&bytes), None);
+        assert!(FixedDec::<u32, 3>::decode(&bytes).is_some());
+    }
+
+    #[test]
+    fn malformed() {
+        let bytes = FixedDec::<u32, 3>::new(1234).encode();
+        assert_eq!(FixedDec::<u32, 3>::decode(&[]), None);
+        assert_eq!(FixedDec::<u32, 3>::decode(&bytes[..4]), None);
+        assert_eq!(FixedDec::<u64, 3>::decode(&bytes), None);
+        assert_eq!(FixedDec::<u16, 3>::decode(&bytes), None);
+        let mut longer = bytes.clone();
+        longer.push(0);
+        assert_eq!(FixedDec::<u32, 3>::decode(&longer), None);
+        // a precision too big for the type
+        assert_eq!(FixedDec::<u8, 3>::decode(&[3, 1]), None);
+    }
+}
@@ -0,0 +1,1004 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+
+use crate::FixedDec;
+use crate::RoundingMode;
+use crate::number::Number;
+
+/// Size of the inline buffer, big enough to hold the digits of any builtin type
+///
+/// The biggest builtin type has 39 digits, to which is added an eventual leading zero,
+/// the dot, the sign and a carry digit from rounding.
+const BUF_SIZE: usize = 64;
+
+/// Buffer used to format a FixedDec, without allocation for the builtin types
+///
+/// The digits of a bigger Number type (e.g. a 256 bits integer) don't fit in the inline
+/// bytes, and are moved to the heap instead, so writing to the buffer never fails.
+pub(crate) struct Buf {
+    inline: [u8; BUF_SIZE],
+    heap: Vec<u8>,
+    len: usize,
+}
+
+impl Buf {
+    pub(crate) const fn new() -> Self {
+        Self {
+            inline: [0; BUF_SIZE],
+            heap: Vec::new(),
+            len: 0,
+        }
+    }
+
+    fn bytes(&self) -> &[u8] {
+        if self.heap.is_empty() {
+            &self.inline
+        } else {
+            &self.heap
+        }
+    }
+
+    fn bytes_mut(&mut self) -> &mut [u8] {
+        if self.heap.is_empty() {
+            &mut self.inline
+        } else {
+            &mut self.heap
+        }
+    }
+
+    /// Make sure the buffer can hold `size` bytes, moving them to the heap if needed
+    fn reserve(&mut self, size: usize) {
+        let capacity = self.bytes().len();
+        if size > capacity {
+            let mut heap = vec![0; size.max(2 * capacity)];
+            heap[..self.len].copy_from_slice(&self.bytes()[..self.len]);
+            self.heap = heap;
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        // only valid str are written through the Write instance
+        core::str::from_utf8(&self.bytes()[..self.len]).unwrap()
+    }
+
+    /// Remove the leading minus sign, if any
+    fn strip_sign(&mut self) {
+        let len = self.len;
+        let bytes = self.bytes_mut();
+        if len > 0 && bytes[0] == b'-' {
+            bytes.copy_within(1..len, 0);
+            self.len -= 1;
+        }
+    }
+
+    /// Whether the digits are written in the raw `e-P` form, see [`FixedDec::write_digits`]
+    fn is_raw(&self) -> bool {
+        self.bytes()[..self.len].contains(&b'e')
+    }
+
+    /// Number of digits after the dot
+    fn fractional_len(&self) -> usize {
+        self.as_str().find('.').map_or(0, |dot| self.len - dot - 1)
+    }
+
+    /// Round the digits of a magnitude to `keep` fractional digits according to `mode`
+    ///
+    /// The rounding is done on the digits themselves, so a carry can grow the integral part
+    /// without any possible overflow (e.g. `9.99` rounded to 1 digit is `10.0`).
+    fn round(&mut self, keep: usize, mode: RoundingMode, negative: bool) {
+        let frac_len = self.fractional_len();
+        if keep >= frac_len {
+            return;
+        }
+        let dot = self.len - frac_len - 1;
+        let cut = dot + 1 + keep;
+        let bytes = self.bytes();
+        let first_dropped = bytes[cut];
+        let rest_nonzero = bytes[cut + 1..self.len].iter().any(|b| *b != b'0');
+        let nonzero = first_dropped != b'0' || rest_nonzero;
+        let last_kept_odd = bytes[if keep == 0 { dot - 1 } else { cut - 1 }] % 2 == 1;
+
+        let away = match mode {
+            RoundingMode::TowardZero => false,
+            RoundingMode::AwayFromZero => nonzero,
+            RoundingMode::Floor => negative && nonzero,
+            RoundingMode::Ceiling => !negative && nonzero,
+            RoundingMode::HalfUp => first_dropped >= b'5',
+            RoundingMode::HalfDown => {
+                first_dropped > b'5' || (first_dropped == b'5' && rest_nonzero)
+            }
+            RoundingMode::HalfEven => {
+                first_dropped > b'5' || (first_dropped == b'5' && (rest_nonzero || last_kept_odd))
+            }
+        };
+
+        // drop the digits, and the dot if no fractional digits are kept
+        self.len = if keep == 0 { dot } else { cut };
+
+        if away {
+            let len = self.len;
+            for b in self.bytes_mut()[..len].iter_mut().rev() {
+                match *b {
+                    b'.' => {}
+                    b'9' => *b = b'0',
+                    _ => {
+                        *b += 1;
+                        return;
+                    }
+                }
+            }
+            // carry over all the digits
+            self.reserve(len + 1);
+            let bytes = self.bytes_mut();
+            bytes.copy_within(0..len, 1);
+            bytes[0] = b'1';
+            self.len += 1;
+        }
+    }
+}
+
+impl Write for Buf {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.reserve(end);
+        let start = self.len;
+        self.bytes_mut()[start..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Writer into a caller provided slice, failing when the slice is full
+struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > self.buf.len() {
+            return Err(fmt::Error);
+        }
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+impl<T: Number, const P: u32> FixedDec<T, P> {
+    /// Write the digits of the magnitude of the value, without the sign
+    ///
+    /// This never panics, even for a value with a precision P too big for T (e.g. created
+    /// by [`FixedDec::try_into`]), which is written as its raw digits followed by `e-P`.
+    pub(crate) fn write_digits(&self, w: &mut Buf) -> fmt::Result {
+        let Some((integral, fractional)) = self.checked_parts() else {
+            write!(w, "{}", self.0)?;
+            w.strip_sign();
+            return write!(w, "e-{P}");
+        };
+        write!(w, "{integral}")?;
+        w.strip_sign();
+        write!(w, ".{:0width$}", fractional, width = P as usize)
+    }
+
+    /// Return the canonical string representation of the value, e.g. to store as a `NUMERIC`
+    ///
+    /// The canonical form has exactly P fractional digits, no grouping and no padding,
+    /// and a `-` sign only for negative values. For P = 0, there's no dot and no fractional
+    /// digits. This form is parsed back by [`FixedDec::from_canonical_str`].
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert_eq!(FixedDec::<i32, 3>::new(-1500).to_canonical_string(), "-1.500");
+    /// assert_eq!(FixedDec::<u32, 0>::new(42).to_canonical_string(), "42");
+    /// ```
+    pub fn to_canonical_string(&self) -> String {
+        let mut s = String::new();
+        // writing into a String never fails
+        let _ = self.write_to(&mut s);
+        if P == 0 && s.ends_with(".0") {
+            s.truncate(s.len() - 2);
+        }
+        s
+    }
+
+    /// Write the decimal representation of the value to any `Write` sink, without allocating
+    ///
+    /// The output is the same as the `Display` output without any formatting flags. Only
+    /// the Number types with more digits than the builtin types need a temporary allocation.
+    ///
+    /// ```
+    /// use core::fmt::Write;
+    /// use fixeddec::FixedDec;
+    /// let mut log = String::new();
+    /// for v in [FixedDec::<i32, 2>::new(-5), FixedDec::new(1250)] {
+    ///     v.write_to(&mut log).unwrap();
+    ///     log.push(' ');
+    /// }
+    /// assert_eq!(log, "-0.05 12.50 ");
+    /// ```
+    pub fn write_to<W: Write>(&self, w: &mut W) -> fmt::Result {
+        let mut buf = Buf::new();
+        self.write_digits(&mut buf)?;
+        if self.0 < T::ZERO {
+            w.write_char('-')?;
+        }
+        w.write_str(buf.as_str())
+    }
+
+    /// Format the value into the given buffer without allocating, returning the written string
+    ///
+    /// The output is the same as the `Display` output. If the buffer is too small to contain it,
+    /// None is returned.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// let mut buf = [0u8; 16];
+    /// let f = FixedDec::<i32, 2>::new(-12345);
+    /// assert_eq!(f.format_into(&mut buf), Some("-123.45"));
+    /// assert_eq!(f.format_into(&mut buf[..4]), None);
+    /// ```
+    pub fn format_into<'a>(&self, buf: &'a mut [u8]) -> Option<&'a str> {
+        let mut w = SliceWriter { buf, len: 0 };
+        self.write_to(&mut w).ok()?;
+        let SliceWriter { buf, len } = w;
+        core::str::from_utf8(&buf[..len]).ok()
+    }
+}
+
+impl<T: Number, const P: u32> fmt::Debug for FixedDec<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/// Write the digits of a number with its sign, followed by a number of trailing zeros,
+/// honoring the width, fill, alignment and zero-padding flags of the formatter
+///
+/// This is similar to `Formatter::pad_integral`, which doesn't allow the trailing zeros.
+fn pad(
+    f: &mut fmt::Formatter<'_>,
+    is_nonnegative: bool,
+    digits: &str,
+    trailing_zeros: usize,
+) -> fmt::Result {
+    let sign = if !is_nonnegative {
+        "-"
+    } else if f.sign_plus() {
+        "+"
+    } else {
+        ""
+    };
+    let len = sign.len() + digits.len() + trailing_zeros;
+    let padding = f.width().map_or(0, |width| width.saturating_sub(len));
+
+    let (pre, post, fill) = if f.sign_aware_zero_pad() {
+        f.write_str(sign)?;
+        (padding, 0, '0')
+    } else {
+        let (pre, post) = match f.align() {
+            None | Some(fmt::Alignment::Right) => (padding, 0),
+            Some(fmt::Alignment::Left) => (0, padding),
+            Some(fmt::Alignment::Center) => (padding / 2, padding.div_ceil(2)),
+        };
+        (pre, post, f.fill())
+    };
+
+    for _ in 0..pre {
+        f.write_char(fill)?;
+    }
+    if !f.sign_aware_zero_pad() {
+        f.write_str(sign)?;
+    }
+    f.write_str(digits)?;
+    for _ in 0..trailing_zeros {
+        f.write_char('0')?;
+    }
+    for _ in 0..post {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+/// Display the decimal value
+///
+/// The width, fill, alignment and zero-padding flags are supported, and the padding
+/// is applied to the integral part, after the sign:
+///
+/// ```
+/// use fixeddec::FixedDec;
+/// let f = FixedDec::<i32, 2>::new(-150);
+/// assert_eq!(format!("{}", f), "-1.50");
+/// assert_eq!(format!("{:08}", f), "-0001.50");
+/// assert_eq!(format!("{:>8}", f), "   -1.50");
+/// ```
+///
+/// The sign flag `+` prefixes the non-negative values, including zero, with a plus sign:
+///
+/// ```
+/// use fixeddec::FixedDec;
+/// assert_eq!(format!("{:+}", FixedDec::<i32, 2>::new(1234)), "+12.34");
+/// assert_eq!(format!("{:+}", FixedDec::<i32, 2>::new(0)), "+0.00");
+/// ```
+///
+/// The precision flag set the number of fractional digits displayed, padding with zeros
+/// when bigger than P, and rounding half away from zero when smaller than P (see
+/// [`FixedDec::with_rounding`] to choose another rounding mode):
+///
+/// ```
+/// use fixeddec::FixedDec;
+/// let f = FixedDec::<u32, 4>::new(12399);
+/// assert_eq!(format!("{:.2}", f), "1.24");
+/// assert_eq!(format!("{:.6}", f), "1.239900");
+/// ```
+impl<T: Number, const P: u32> fmt::Display for FixedDec<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with(f, '.', RoundingMode::HalfUp)
+    }
+}
+
+impl<T: Number, const P: u32> FixedDec<T, P> {
+    /// Display the value using `sep` as the decimal separator
+    ///
+    /// All the formatting flags supported by `Display` are also supported by the wrapper.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// let f = FixedDec::<i32, 2>::new(-1234);
+    /// assert_eq!(format!("{}", f.with_separator(',').unwrap()), "-12,34");
+    /// assert_eq!(format!("{:>8.1}", f.with_separator(',').unwrap()), "   -12,3");
+    /// assert!(f.with_separator('-').is_none());
+    /// ```
+    ///
+    /// Like [`FixedDec::from_str_with_separator`], the separator can't be a digit or `-`,
+    /// as the output couldn't be parsed back, in which case None is returned.
+    pub fn with_separator(self, sep: char) -> Option<WithSeparator<T, P>> {
+        (!sep.is_ascii_digit() && sep != '-').then_some(WithSeparator { value: self, sep })
+    }
+
+    /// Display the value with a rounding mode used when the precision flag drops digits
+    ///
+    /// The default `Display` rounds half away from zero, and this wrapper allows
+    /// to use another rounding mode, for example to display with the banker's rounding.
+    ///
+    /// ```
+    /// use fixeddec::{FixedDec, RoundingMode};
+    /// let f = FixedDec::<u32, 3>::new(1_125);
+    /// assert_eq!(format!("{:.2}", f), "1.13");
+    /// assert_eq!(format!("{:.2}", f.with_rounding(RoundingMode::HalfEven)), "1.12");
+    /// ```
+    pub fn with_rounding(self, mode: RoundingMode) -> WithRounding<T, P> {
+        WithRounding { value: self, mode }
+    }
+
+    /// Return the decimal representation of the value with `digits` fractional digits,
+    /// rounding the dropped digits according to `mode`
+    ///
+    /// The value itself is unchanged, this is equivalent to formatting
+    /// [`FixedDec::with_rounding`] with the precision flag, and pads with zeros
+    /// when `digits` is bigger than P.
+    ///
+    /// ```
+    /// use fixeddec::{FixedDec, RoundingMode};
+    /// let f = FixedDec::<u64, 6>::new(1_234_567);
+    /// assert_eq!(f.display_rounded(2, RoundingMode::HalfUp), "1.23");
+    /// assert_eq!(f.display_rounded(2, RoundingMode::Ceiling), "1.24");
+    /// ```
+    pub fn display_rounded(self, digits: u32, mode: RoundingMode) -> String {
+        format!("{:.*}", digits as usize, self.with_rounding(mode))
+    }
+
+    /// Display the value as an amount of money, e.g. `$1,234.56`
+    ///
+    /// The amount is always displayed with exactly 2 fractional digits, rounding half away
+    /// from zero or padding with zeros, with the integral digits grouped by thousands and
+    /// prefixed by the currency symbol. The negative amounts are displayed as `-$1.00`, or as
+    /// `($1.00)` with [`Currency::parentheses`]. The width, fill and alignment flags are
+    /// supported, and the amount is aligned to the right by default like the numbers.
+    ///
+    /// A value whose precision P is too big for T (e.g. created by [`FixedDec::try_into`])
+    /// can't be grouped nor rounded, and is displayed in the same raw form as `Display`.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// let f = FixedDec::<i64, 3>::new(-1_234_567);
+    /// assert_eq!(format!("{}", f.currency("$")), "-$1,234.57");
+    /// assert_eq!(format!("{}", f.currency("$").parentheses()), "($1,234.57)");
+    /// assert_eq!(format!("{}", FixedDec::<u32, 0>::new(5).currency("€")), "€5.00");
+    /// ```
+    pub fn currency(self, symbol: &str) -> Currency<'_, T, P> {
+        Currency {
+            value: self,
+            symbol,
+            parentheses: false,
+        }
+    }
+
+    fn fmt_with(&self, f: &mut fmt::Formatter<'_>, sep: char, mode: RoundingMode) -> fmt::Result {
+        let negative = self.0 < T::ZERO;
+        let mut buf = Buf::new();
+        self.write_digits(&mut buf)?;
+        let mut trailing_zeros = 0;
+        if let Some(precision) = f.precision().filter(|_| !buf.is_raw()) {
+            let frac_len = buf.fractional_len();
+            if precision < frac_len {
+                buf.round(precision, mode, negative);
+            } else {
+                trailing_zeros = precision - frac_len;
+            }
+        }
+        if sep != '.' {
+            if let Some((integral, fractional)) = buf.as_str().split_once('.') {
+                let mut localized = Buf::new();
+                write!(localized, "{integral}{sep}{fractional}")?;
+                return pad(f, !negative, localized.as_str(), trailing_zeros);
+            }
+        }
+        pad(f, !negative, buf.as_str(), trailing_zeros)
+    }
+}
+
+/// Display wrapper of a FixedDec using a custom decimal separator
+///
+/// Created by [`FixedDec::with_separator`]
+#[derive(Clone, Copy, Debug)]
+pub struct WithSeparator<T: Number, const P: u32> {
+    value: FixedDec<T, P>,
+    sep: char,
+}
+
+impl<T: Number, const P: u32> fmt::Display for WithSeparator<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt_with(f, self.sep, RoundingMode::HalfUp)
+    }
+}
+
+/// Display wrapper of a FixedDec using a custom rounding mode for the precision flag
+///
+/// Created by [`FixedDec::with_rounding`]
+#[derive(Clone, Copy, Debug)]
+pub struct WithRounding<T: Number, const P: u32> {
+    value: FixedDec<T, P>,
+    mode: RoundingMode,
+}
+
+impl<T: Number, const P: u32> fmt::Display for WithRounding<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt_with(f, '.', self.mode)
+    }
+}
+
+/// Display wrapper of a FixedDec as an amount of money
+///
+/// Created by [`FixedDec::currency`]
+#[derive(Clone, Copy, Debug)]
+pub struct Currency<'a, T: Number, const P: u32> {
+    value: FixedDec<T, P>,
+    symbol: &'a str,
+    parentheses: bool,
+}
+
+impl<T: Number, const P: u32> Currency<'_, T, P> {
+    /// Display the negative amounts between parentheses instead of with a minus sign,
+    /// as commonly done in accounting
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// let f = FixedDec::<i32, 2>::new(-100);
+    /// assert_eq!(format!("{}", f.currency("$").parentheses()), "($1.00)");
+    /// ```
+    pub fn parentheses(self) -> Self {
+        Self {
+            parentheses: true,
+            ..self
+        }
+    }
+}
+
+impl<T: Number, const P: u32> fmt::Display for Currency<'_, T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = Buf::new();
+        self.value.write_digits(&mut buf)?;
+        // the raw form can't be grouped nor rounded, and is displayed as is
+        if buf.is_raw() {
+            return fmt::Display::fmt(&self.value, f);
+        }
+        let negative = self.value.0 < T::ZERO;
+        buf.round(2, RoundingMode::HalfUp, negative);
+        let (integral, fractional) = buf.as_str().split_once('.').unwrap_or((buf.as_str(), ""));
+        // a negative amount rounded to zero is displayed without sign
+        let negative = negative
+            && integral
+                .bytes()
+                .chain(fractional.bytes())
+                .any(|b| b != b'0');
+
+        let mut out = Buf::new();
+        if negative {
+            out.write_char(if self.parentheses { '(' } else { '-' })?;
+        }
+        out.write_str(self.symbol)?;
+        for (i, c) in integral.char_indices() {
+            if i > 0 && (integral.len() - i) % 3 == 0 {
+                out.write_char(',')?;
+            }
+            out.write_char(c)?;
+        }
+        write!(out, ".{fractional:0<2}")?;
+        if negative && self.parentheses {
+            out.write_char(')')?;
+        }
+
+        // aligned to the right like the numbers, and without the truncation to the
+        // precision flag done by `Formatter::pad`
+        let out = out.as_str();
+        let padding = f
+            .width()
+            .map_or(0, |width| width.saturating_sub(out.chars().count()));
+        let (pre, post) = match f.align() {
+            None | Some(fmt::Alignment::Right) => (padding, 0),
+            Some(fmt::Alignment::Left) => (0, padding),
+            Some(fmt::Alignment::Center) => (padding / 2, padding.div_ceil(2)),
+        };
+        for _ in 0..pre {
+            f.write_char(f.fill())?;
+        }
+        f.write_str(out)?;
+        for _ in 0..post {
+            f.write_char(f.fill())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::ten_power;
+    use alloc::string::ToString;
+
+    #[test]
+    fn sign() {
+        assert_eq!(format!("{}", FixedDec::<i32, 3>::new(-1234)), "-1.234");
+        assert_eq!(format!("{}", FixedDec::<i32, 3>::new(-500)), "-0.500");
+        assert_eq!(format!("{}", FixedDec::<i32, 3>::new(-5)), "-0.005");
+        assert_eq!(format!("{}", FixedDec::<i32, 3>::new(0)), "0.000");
+        assert_eq!(format!("{}", FixedDec::<i8, 1>::MIN), "-12.8");
+        assert_eq!(format!("{}", FixedDec::<i8, 2>::MIN), "-1.28");
+        assert_eq!(
+            format!("{}", FixedDec::<i128, 0>::MIN),
+            "-170141183460469231731687303715884105728.0"
+        );
+        assert_eq!(format!("{:?}", FixedDec::<i32, 2>::new(-5)), "-0.05");
+    }
+
+    #[test]
+    fn padding() {
+        let x1 = FixedDec::<u32, 2>::new(150);
+        let x2 = FixedDec::<i32, 2>::new(-150);
+        let x3 = FixedDec::<i32, 2>::new(-5);
+
+        assert_eq!(format!("{:08}", x1), "00001.50");
+        assert_eq!(format!("{:8}", x1), "    1.50");
+        assert_eq!(format!("{:<8}|", x1), "1.50    |");
+        assert_eq!(format!("{:^8}", x1), "  1.50  ");
+        assert_eq!(format!("{:*>8}", x1), "****1.50");
+        assert_eq!(format!("{:2}", x1), "1.50");
+
+        assert_eq!(format!("{:08}", x2), "-0001.50");
+        assert_eq!(format!("{:8}", x2), "   -1.50");
+        assert_eq!(format!("{:<8}|", x2), "-1.50   |");
+        assert_eq!(format!("{:06}", x3), "-00.05");
+    }
+
+    #[test]
+    fn sign_plus() {
+        let p = FixedDec::<i32, 2>::new(1234);
+        let n = FixedDec::<i32, 2>::new(-1234);
+        let z = FixedDec::<i32, 2>::new(0);
+
+        assert_eq!(format!("{:+}", p), "+12.34");
+        assert_eq!(format!("{:+}", n), "-12.34");
+        assert_eq!(format!("{:+}", z), "+0.00");
+        assert_eq!(format!("{:+}", FixedDec::<u32, 1>::new(5)), "+0.5");
+
+        // combined with the other flags
+        assert_eq!(format!("{:+08}", p), "+0012.34");
+        assert_eq!(format!("{:+8}", p), "  +12.34");
+        assert_eq!(format!("{:<+8}|", p), "+12.34  |");
+        assert_eq!(format!("{:+.1}", p), "+12.3");
+        assert_eq!(format!("{:+.0}", FixedDec::<i32, 2>::new(-49)), "-0");
+        assert_eq!(format!("{:+}", p.with_separator(',').unwrap()), "+12,34");
+    }
+
+    #[test]
+    fn precision() {
+        let x1 = FixedDec::<u32, 4>::new(12399);
+        let x2 = FixedDec::<u32, 3>::new(9999);
+        let i1 = FixedDec::<i32, 3>::new(-9995);
+
+        assert_eq!(format!("{:.2}", x1), "1.24");
+        assert_eq!(format!("{:.3}", x1), "1.240");
+        assert_eq!(format!("{:.4}", x1), "1.2399");
+        assert_eq!(format!("{:.6}", x1), "1.239900");
+        assert_eq!(format!("{:.1}", x1), "1.2");
+        assert_eq!(format!("{:.0}", x1), "1");
+
+        // half boundary
+        assert_eq!(format!("{:.2}", FixedDec::<u32, 3>::new(1235)), "1.24");
+        assert_eq!(format!("{:.2}", FixedDec::<u32, 3>::new(1234)), "1.23");
+        assert_eq!(format!("{:.0}", FixedDec::<u32, 1>::new(25)), "3");
+        assert_eq!(format!("{:.0}", FixedDec::<u32, 1>::new(24)), "2");
+
+        // carry propagation
+        assert_eq!(format!("{:.2}", x2), "10.00");
+        assert_eq!(format!("{:.0}", x2), "10");
+        assert_eq!(format!("{:.2}", i1), "-10.00");
+        assert_eq!(format!("{:.1}", FixedDec::<u8, 1>::MAX), "25.5");
+        assert_eq!(format!("{:.0}", FixedDec::<u8, 1>::MAX), "26");
+        assert_eq!(
+            format!("{:.0}", FixedDec::<u128, 1>::MAX),
+            "34028236692093846346337460743176821146"
+        );
+
+        // with width
+        assert_eq!(format!("{:08.2}", i1), "-0010.00");
+        assert_eq!(format!("{:>8.1}", x1), "     1.2");
+        assert_eq!(format!("{:^9.5}", x1), " 1.23990 ");
+        assert_eq!(format!("{:.40}", x1).len(), 42);
+    }
+
+    #[test]
+    fn separator() {
+        let x1 = FixedDec::<u32, 2>::new(1234);
+        let i1 = FixedDec::<i32, 3>::new(-9995);
+
+        assert_eq!(format!("{}", x1.with_separator(',').unwrap()), "12,34");
+        assert_eq!(format!("{}", x1.with_separator('.').unwrap()), "12.34");
+        assert_eq!(format!("{}", x1.with_separator('·').unwrap()), "12·34");
+        assert_eq!(
+            format!("{:08}", x1.with_separator(',').unwrap()),
+            "00012,34"
+        );
+        assert_eq!(format!("{:.2}", i1.with_separator(',').unwrap()), "-10,00");
+        assert_eq!(format!("{:.0}", i1.with_separator(',').unwrap()), "-10");
+        assert_eq!(
+            format!("{:.5}", i1.with_separator(',').unwrap()),
+            "-9,99500"
+        );
+
+        let parsed = FixedDec::<u32, 2>::from_str_with_separator("12,34", ',');
+        assert_eq!(parsed, Some(x1));
+
+        // the separators rejected by the parser are rejected too
+        assert!(x1.with_separator('5').is_none());
+        assert!(x1.with_separator('0').is_none());
+        assert!(i1.with_separator('-').is_none());
+        assert_eq!(
+            FixedDec::<u32, 2>::from_str_with_separator("12534", '5'),
+            None
+        );
+    }
+
+    #[test]
+    fn currency() {
+        let money = |v: FixedDec<i64, 2>| format!("{}", v.currency("$"));
+        let accounting = |v: FixedDec<i64, 2>| format!("{}", v.currency("$").parentheses());
+
+        assert_eq!(money(FixedDec::new(123456)), "$1,234.56");
+        assert_eq!(money(FixedDec::new(100)), "$1.00");
+        assert_eq!(money(FixedDec::new(5)), "$0.05");
+        assert_eq!(money(FixedDec::new(100_000_000)), "$1,000,000.00");
+        assert_eq!(money(FixedDec::new(12_345_678)), "$123,456.78");
+        assert_eq!(money(FixedDec::MAX), "$92,233,720,368,547,758.07");
+
+        assert_eq!(money(FixedDec::new(-100)), "-$1.00");
+        assert_eq!(money(FixedDec::new(-123456)), "-$1,234.56");
+        assert_eq!(money(FixedDec::MIN), "-$92,233,720,368,547,758.08");
+        assert_eq!(accounting(FixedDec::new(-100)), "($1.00)");
+        assert_eq!(accounting(FixedDec::new(-123456)), "($1,234.56)");
+        assert_eq!(accounting(FixedDec::new(123456)), "$1,234.56");
+
+        assert_eq!(money(FixedDec::new(0)), "$0.00");
+        assert_eq!(accounting(FixedDec::new(0)), "$0.00");
+
+        // rounding and padding to 2 digits
+        let f = FixedDec::<i32, 4>::new(-9_999_995);
+        assert_eq!(format!("{}", f.currency("$")), "-$1,000.00");
+        let f = FixedDec::<i32, 3>::new(-4);
+        assert_eq!(format!("{}", f.currency("$").parentheses()), "$0.00");
+        let f = FixedDec::<u8, 1>::new(255);
+        assert_eq!(format!("{}", f.currency("€")), "€25.50");
+        let f = FixedDec::<u16, 0>::new(1000);
+        assert_eq!(format!("{}", f.currency("CHF ")), "CHF 1,000.00");
+
+        // width, fill and alignment
+        let f = FixedDec::<u32, 2>::new(150);
+        assert_eq!(format!("[{:>12}]", f.currency("$")), "[       $1.50]");
+        assert_eq!(format!("[{:12}]", f.currency("$")), "[       $1.50]");
+        assert_eq!(format!("[{:<8}]", f.currency("$")), "[$1.50   ]");
+        assert_eq!(format!("[{:*^9}]", f.currency("€")), "[**€1.50**]");
+        assert_eq!(format!("[{:3}]", f.currency("$")), "[$1.50]");
+        assert_eq!(format!("[{:>8.1}]", f.currency("$")), "[   $1.50]");
+        let f = FixedDec::<i32, 2>::new(-123_456);
+        assert_eq!(
+            format!("[{:>12}]", f.currency("$").parentheses()),
+            "[ ($1,234.56)]"
+        );
+
+        // a precision too big for the type is displayed in the raw form
+        let f = FixedDec::<u16, 3>::new(123).try_into::<u8>().unwrap();
+        assert_eq!(format!("{}", f.currency("$")), "123e-3");
+        assert_eq!(format!("{}", f.currency("$")), f.to_string());
+        let f = FixedDec::<i16, 3>::new(-123).try_into::<i8>().unwrap();
+        assert_eq!(format!("{}", f.currency("$").parentheses()), "-123e-3");
+    }
+
+    #[test]
+    fn max_precision() {
+        fn check<T: Number, const P: u32>() {
+            let five = T::from_digit10('5').unwrap();
+            // P is the maximum precision of T
+            assert!(ten_power::<T>(P + 1).is_none());
+            let expected = format!("0.{}5", "0".repeat(P as usize - 1));
+            assert_eq!(FixedDec::<T, P>::new(five).to_string(), expected);
+            assert_eq!(FixedDec::<T, P>::new(five).to_canonical_string(), expected);
+            let expected = format!("0.{}", "0".repeat(P as usize));
+            assert_eq!(FixedDec::<T, P>::new(T::ZERO).to_string(), expected);
+            let expected = format!("1.{}", "0".repeat(P as usize));
+            assert_eq!(
+                FixedDec::<T, P>::from_integral(T::ONE).unwrap().to_string(),
+                expected
+            );
+        }
+        check::<u8, 2>();
+        check::<i8, 2>();
+        check::<u16, 4>();
+        check::<u32, 9>();
+        check::<u64, 19>();
+        check::<u128, 38>();
+        check::<i16, 4>();
+        check::<i32, 9>();
+        check::<i64, 18>();
+        check::<i128, 38>();
+
+        let f = FixedDec::<u128, 38>::new(5);
+        assert_eq!(f.to_string(), "0.00000000000000000000000000000000000005");
+        assert_eq!(f.to_string().len(), 40);
+        assert_eq!(
+            format!("{:.37}", f),
+            "0.0000000000000000000000000000000000001"
+        );
+        assert_eq!(
+            format!("{:.40}", f),
+            "0.0000000000000000000000000000000000000500"
+        );
+        assert_eq!(
+            FixedDec::<i128, 38>::new(-5).to_string(),
+            "-0.00000000000000000000000000000000000005"
+        );
+        assert_eq!(
+            FixedDec::<i128, 38>::MIN.to_string(),
+            "-1.70141183460469231731687303715884105728"
+        );
+        assert_eq!(
+            FixedDec::<u64, 19>::MAX.to_string(),
+            "1.8446744073709551615"
+        );
+        assert_eq!(
+            FixedDec::<u128, 38>::from_str("0.00000000000000000000000000000000000005"),
+            Some(f)
+        );
+    }
+
+    #[test]
+    fn boundary_precision() {
+        assert_eq!(format!("{}", FixedDec::<u8, 2>::MAX), "2.55");
+        assert_eq!(format!("{:?}", FixedDec::<i8, 2>::MIN), "-1.28");
+        assert_eq!(
+            format!("{}", FixedDec::<u128, 38>::MAX),
+            "3.40282366920938463463374607431768211455"
+        );
+        assert_eq!(format!("{:.1}", FixedDec::<i128, 38>::MIN), "-1.7");
+
+        // a precision too big for the type can be reached by converting the backing type,
+        // and is displayed in the raw form without panicking
+        let f = FixedDec::<i64, 10>::new(-5).try_into::<i8>().unwrap();
+        assert_eq!(format!("{}", f), "-5e-10");
+        assert_eq!(format!("{:?}", f), "-5e-10");
+        assert_eq!(format!("{:>8.2}", f), "  -5e-10");
+        assert_eq!(f.to_canonical_string(), "-5e-10");
+        let f = FixedDec::<u128, 38>::MAX.try_into::<u8>();
+        assert!(f.is_err());
+        let f = FixedDec::<i128, 38>::new(i128::from(i8::MIN))
+            .try_into::<i8>()
+            .unwrap();
+        assert_eq!(format!("{}", f), "-128e-38");
+        let mut buf = [0u8; 16];
+        assert_eq!(f.format_into(&mut buf), Some("-128e-38"));
+    }
+
+    #[test]
+    fn canonical() {
+        let values = [
+            FixedDec::<i64, 4>::new(0),
+            FixedDec::<i64, 4>::new(-5),
+            FixedDec::<i64, 4>::new(-123_4567),
+            FixedDec::<i64, 4>::MAX,
+            FixedDec::<i64, 4>::MIN,
+        ];
+        let expected = [
+            "0.0000",
+            "-0.0005",
+            "-123.4567",
+            "922337203685477.5807",
+            "-922337203685477.5808",
+        ];
+        for (v, e) in values.iter().zip(expected) {
+            assert_eq!(v.to_canonical_string(), e);
+            assert_eq!(FixedDec::from_canonical_str(e), Some(*v));
+        }
+
+        let max = FixedDec::<u128, 0>::MAX;
+        assert_eq!(max.to_canonical_string(), u128::MAX.to_string());
+        assert_eq!(
+            FixedDec::from_canonical_str(&u128::MAX.to_string()),
+            Some(max)
+        );
+        assert_eq!(FixedDec::<u32, 0>::new(0).to_canonical_string(), "0");
+
+        assert_eq!(FixedDec::<i64, 4>::from_canonical_str("1.5"), None);
+        assert_eq!(FixedDec::<i64, 4>::from_canonical_str("1.50000"), None);
+        assert_eq!(FixedDec::<i64, 4>::from_canonical_str("1"), None);
+
+        // leading zeros and signed zeros are not canonical
+        assert_eq!(FixedDec::<i64, 2>::from_canonical_str("007.50"), None);
+        assert_eq!(FixedDec::<i64, 2>::from_canonical_str("-07.50"), None);
+        assert_eq!(FixedDec::<i64, 2>::from_canonical_str("00.50"), None);
+        assert_eq!(FixedDec::<u32, 0>::from_canonical_str("01"), None);
+        assert_eq!(FixedDec::<i64, 2>::from_canonical_str("-0.00"), None);
+        assert_eq!(FixedDec::<i64, 2>::from_canonical_str("-000.00"), None);
+        assert_eq!(FixedDec::<i64, 0>::from_canonical_str("-0"), None);
+        assert_eq!(
+            FixedDec::<i64, 2>::from_canonical_str("0.50"),
+            Some(FixedDec::new(50))
+        );
+        assert_eq!(
+            FixedDec::<i64, 2>::from_canonical_str("-0.50"),
+            Some(FixedDec::new(-50))
+        );
+        assert_eq!(
+            FixedDec::<i64, 2>::from_canonical_str("10.00"),
+            Some(FixedDec::new(1000))
+        );
+        assert_eq!(FixedDec::<i64, 4>::from_canonical_str(".5000"), None);
+        assert_eq!(FixedDec::<i64, 4>::from_canonical_str("-.5000"), None);
+        assert_eq!(FixedDec::<u32, 0>::from_canonical_str("1.0"), None);
+        assert_eq!(FixedDec::<u32, 0>::from_canonical_str("1."), None);
+    }
+
+    #[test]
+    fn write_to() {
+        // fixed capacity writer, similar to heapless::String
+        struct Fixed<const N: usize> {
+            bytes: [u8; N],
+            len: usize,
+        }
+        impl<const N: usize> Write for Fixed<N> {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                let end = self.len + s.len();
+                if end > N {
+                    return Err(fmt::Error);
+                }
+                self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+                self.len = end;
+                Ok(())
+            }
+        }
+
+        let mut w = Fixed::<16> {
+            bytes: [0; 16],
+            len: 0,
+        };
+        FixedDec::<i32, 3>::new(-1234).write_to(&mut w).unwrap();
+        w.write_char(';').unwrap();
+        FixedDec::<u8, 0>::new(7).write_to(&mut w).unwrap();
+        assert_eq!(&w.bytes[..w.len], b"-1.234;7.0");
+        assert!(FixedDec::<u64, 2>::MAX.write_to(&mut w).is_err());
+
+        for v in [0, -5, 12345, i64::MIN, i64::MAX] {
+            let f = FixedDec::<i64, 4>::new(v);
+            let mut s = String::new();
+            f.write_to(&mut s).unwrap();
+            assert_eq!(s, format!("{}", f));
+        }
+    }
+
+    #[test]
+    fn rounding() {
+        use RoundingMode::*;
+
+        let x1 = FixedDec::<u32, 3>::new(1_125);
+        let x2 = FixedDec::<u32, 3>::new(1_135);
+        let i1 = FixedDec::<i32, 3>::new(-1_125);
+
+        assert_eq!(format!("{:.2}", x1.with_rounding(HalfUp)), "1.13");
+        assert_eq!(format!("{:.2}", x1.with_rounding(HalfEven)), "1.12");
+        assert_eq!(format!("{:.2}", x2.with_rounding(HalfEven)), "1.14");
+        assert_eq!(format!("{:.2}", x1.with_rounding(HalfDown)), "1.12");
+        assert_eq!(format!("{:.1}", x1.with_rounding(Ceiling)), "1.2");
+        assert_eq!(format!("{:.0}", x1.with_rounding(TowardZero)), "1");
+        assert_eq!(format!("{:.2}", i1.with_rounding(HalfEven)), "-1.12");
+        assert_eq!(format!("{:.2}", i1.with_rounding(Floor)), "-1.13");
+        assert_eq!(format!("{:.2}", i1.with_rounding(Ceiling)), "-1.12");
+
+        // no rounding without the precision flag or with more digits
+        assert_eq!(format!("{}", x1.with_rounding(Floor)), "1.125");
+        assert_eq!(format!("{:.4}", x1.with_rounding(Floor)), "1.1250");
+        assert_eq!(format!("{:>8.2}", x1.with_rounding(HalfEven)), "    1.12");
+    }
+
+    #[test]
+    fn display_rounded() {
+        use RoundingMode::*;
+
+        let f = FixedDec::<u64, 6>::from_str("2.345001").unwrap();
+        assert_eq!(f.display_rounded(2, HalfUp), "2.35");
+        assert_eq!(f.display_rounded(2, HalfDown), "2.35");
+        assert_eq!(f.display_rounded(2, HalfEven), "2.35");
+        assert_eq!(f.display_rounded(2, TowardZero), "2.34");
+        assert_eq!(f.display_rounded(2, Floor), "2.34");
+        assert_eq!(f.display_rounded(2, Ceiling), "2.35");
+        assert_eq!(f.to_string(), "2.345001");
+
+        let f = FixedDec::<i64, 6>::from_str("-2.345000").unwrap();
+        assert_eq!(f.display_rounded(2, HalfUp), "-2.35");
+        assert_eq!(f.display_rounded(2, HalfDown), "-2.34");
+        assert_eq!(f.display_rounded(2, HalfEven), "-2.34");
+        assert_eq!(f.display_rounded(2, Floor), "-2.35");
+        assert_eq!(f.display_rounded(2, Ceiling), "-2.34");
+        assert_eq!(f.display_rounded(2, AwayFromZero), "-2.35");
+
+        let f = FixedDec::<u64, 6>::from_str("9.999999").unwrap();
+        assert_eq!(f.display_rounded(2, HalfUp), "10.00");
+        assert_eq!(f.display_rounded(0, TowardZero), "9");
+        assert_eq!(f.display_rounded(8, TowardZero), "9.99999900");
+    }
+
+    #[test]
+    fn format_into() {
+        let mut buf = [0u8; 64];
+        let x1 = FixedDec::<u32, 2>::new(12345);
+        let i1 = FixedDec::<i32, 2>::new(-5);
+
+        assert_eq!(x1.format_into(&mut buf), Some("123.45"));
+        assert_eq!(x1.format_into(&mut buf[..6]), Some("123.45"));
+        assert_eq!(x1.format_into(&mut buf[..5]), None);
+        assert_eq!(x1.format_into(&mut []), None);
+        assert_eq!(i1.format_into(&mut buf[..5]), Some("-0.05"));
+        assert_eq!(i1.format_into(&mut buf[..4]), None);
+
+        let max = FixedDec::<i128, 0>::MIN;
+        assert_eq!(max.format_into(&mut buf), Some(format!("{}", max).as_str()));
+    }
+
+    #[test]
+    fn buffer_spill() {
+        let mut buf = Buf::new();
+        assert!(buf.write_str(&"1".repeat(BUF_SIZE)).is_ok());
+        assert!(buf.heap.is_empty());
+        assert!(buf.write_str("2").is_ok());
+        assert!(!buf.heap.is_empty());
+        assert_eq!(buf.as_str(), "1".repeat(BUF_SIZE) + "2");
+
+        // rounding in the heap, with a carry over all the digits
+        let mut buf = Buf::new();
+        write!(buf, "{}.9", "9".repeat(BUF_SIZE)).unwrap();
+        buf.round(0, RoundingMode::HalfUp, false);
+        assert_eq!(buf.as_str(), "1".to_string() + &"0".repeat(BUF_SIZE));
+    }
+}
@@ -33,9 +33,21 @@
 //! type Nano = FixedDec<u64, 9>;
 //! ```
 //!
+//! and the values moved between those precisions with [`FixedDec::to_milli`],
+//! [`FixedDec::to_micro`] and [`FixedDec::to_nano`]:
+//!
+//! ```
+//! # use fixeddec::FixedDec;
+//! # type Milli = FixedDec<u64, 3>;
+//! # type Micro = FixedDec<u64, 6>;
+//! let delay = Milli::new(1_500);
+//! assert_eq!(delay.to_micro(), Some(Micro::new(1_500_000)));
+//! ```
+//!
 //! ## Type Parameters
 //!
-//! - `T`: The underlying integer type which is currently limited to rust builtin integer types (e.g., `i32`, `u64`)
+//! - `T`: The underlying integer type, which is any rust builtin integer type (e.g., `i32`, `u64`),
+//!   or any other integer type implementing [`Number`]
 //! - `P`: A compile-time constant specifying the number of fractional decimal digits.
 //!
 //! ## Use Cases
@@ -63,43 +75,83 @@
 //! assert_eq!(point_one + point_two, point_three);
 //! ```
 //!
+//! ## Overflow
+//!
+//! The operators (`+`, `-`, `*`, `/`) follow the semantics of the builtin integer operators:
+//! they panic on overflow in debug builds, and silently wrap in release builds. When a wrong
+//! value is not acceptable, use the checked methods (e.g. [`FixedDec::checked_add`]) which
+//! return None on overflow, or the [`StrictFixedDec`] wrapper whose operators panic on
+//! overflow in all the build profiles.
+//!
+//! ## Constants
+//!
+//! Constants can be defined from a decimal literal, which is parsed at compile time:
+//!
+//! ```rust
+//! use fixeddec::FixedDec;
+//!
+//! const RATE: FixedDec<u64, 4> = FixedDec::<u64, 4>::from_str_const("0.0525");
+//! assert_eq!(RATE.value(), 525);
+//! ```
+//!
+//! A malformed literal, or a literal that doesn't fit, fails to compile:
+//!
+//! ```compile_fail
+//! use fixeddec::FixedDec;
+//!
+//! const RATE: FixedDec<u64, 4> = FixedDec::<u64, 4>::from_str_const("0.05x");
+//! ```
+//!
 #![no_std]
 
-use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use core::iter::Product;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
 
 extern crate alloc;
 
+mod acc;
 pub mod constants;
+mod convert;
+#[cfg(feature = "rust_decimal")]
+pub mod decimal;
+mod dynamic;
+mod encode;
+mod fmt;
+mod nonzero;
 mod number;
+mod parse;
+#[cfg(feature = "rand")]
+pub mod random;
+mod rounding;
+mod strict;
 
-use number::{Number, ten_power};
+pub use acc::FixedDecAcc;
+pub use convert::TryFromFixedDecError;
+pub use dynamic::DynFixedDec;
+pub use fmt::{Currency, WithRounding, WithSeparator};
+pub use nonzero::NonZeroFixedDec;
+pub use number::{Number, SignedNumber, ten_power};
+pub use parse::{ParseFixedDecError, parse_decimal};
+pub use rounding::RoundingMode;
+pub use strict::StrictFixedDec;
 
 /// A integral number with a precision of fractional digits
 ///
 /// At P=0, it is a normal integer with no fractional part
 ///
+/// Equality, ordering and hashing are all defined on the raw backing value, so for values
+/// of the same type `a == b` implies `hash(a) == hash(b)`, and the hash of a FixedDec is
+/// the same as the hash of its raw value. As the backing values are integers, there's no
+/// negative zero, and each represented value has a single raw value.
+///
+/// Values of different precisions are different types, see [`FixedDec::eq_value`] to compare them.
+///
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct FixedDec<T: Number, const P: u32>(T);
 
-impl<T: Number, const P: u32> alloc::fmt::Debug for FixedDec<T, P> {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(
-            f,
-            "{}.{:0width$}",
-            self.integral(),
-            self.fractional(),
-            width = P as usize
-        )
-    }
-}
-
-impl<T: Number, const P: u32> alloc::fmt::Display for FixedDec<T, P> {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{:?}", self)
-    }
-}
-
 impl<T: Number, const P: u32> FixedDec<T, P> {
     /// Minimum value representable by this type
     pub const MIN: Self = Self::new(T::MIN);
@@ -107,6 +159,24 @@ impl<T: Number, const P: u32> FixedDec<T, P> {
     /// Maximum value representable by this type
     pub const MAX: Self = Self::new(T::MAX);
 
+    /// The value zero, the identity of the addition
+    pub const ZERO: Self = Self::new(T::ZERO);
+
+    /// The value one, represented by the raw value `10^P`
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert_eq!(FixedDec::<u32, 3>::ONE, FixedDec::from_integral(1).unwrap());
+    /// ```
+    ///
+    /// When `10^P` doesn't fit in T, using this constant is a compile error:
+    ///
+    /// ```compile_fail
+    /// use fixeddec::FixedDec;
+    /// let _ = FixedDec::<u8, 3>::ONE;
+    /// ```
+    pub const ONE: Self = Self::new(T::TEN_POWER[P as usize]);
+
     /// Create a new FixedDec using the backing value already at the required precision
     ///
     /// ```
@@ -119,6 +189,63 @@ impl<T: Number, const P: u32> FixedDec<T, P> {
         Self(t)
     }
 
+    /// Create a new FixedDec using the backing value already at the required precision,
+    /// returning None instead of panicking if the precision P is too large for T
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert_eq!(FixedDec::<u8, 2>::new_checked(123), Some(FixedDec::new(123)));
+    /// assert!(FixedDec::<u8, 3>::new_checked(123).is_none());
+    /// ```
+    pub const fn new_checked(t: T) -> Option<Self> {
+        if T::TEN_POWER.len() > P as usize {
+            Some(Self(t))
+        } else {
+            None
+        }
+    }
+
+    /// Return the precision P of this type, the number of fractional digits
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert_eq!(FixedDec::<u32, 3>::new(0).scale(), 3);
+    /// ```
+    pub const fn scale(self) -> u32 {
+        P
+    }
+
+    /// Return the scale factor of this type, which is 10 to the power of P
+    ///
+    /// This is the raw value representing `1` at precision P.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert_eq!(FixedDec::<u32, 3>::scale_factor(), 1000);
+    /// ```
+    ///
+    /// Panics if the precision P is too big for T, see [`ten_power`] for a checked version
+    pub const fn scale_factor() -> T {
+        assert!(T::TEN_POWER.len() > P as usize);
+        T::TEN_POWER[P as usize]
+    }
+
+    /// Return `10^shift` as a decimal, e.g. `0.01` for a shift of -2 or `100` for a shift of 2
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert_eq!(FixedDec::<u32, 3>::scaled_one(-2), Some(FixedDec::new(10)));
+    /// assert_eq!(FixedDec::<u32, 3>::scaled_one(2), Some(FixedDec::new(100_000)));
+    /// ```
+    ///
+    /// If `shift` is smaller than `-P` (the value is smaller than the precision), or if the
+    /// raw value `10^(P + shift)` doesn't fit in T, then None is returned
+    pub fn scaled_one(shift: i32) -> Option<Self> {
+        let exponent = i64::from(P) + i64::from(shift);
+        let raw = ten_power::<T>(u32::try_from(exponent).ok()?)?;
+        Self::new_checked(raw)
+    }
+
     /// Create a new FixedDec using the backing value as just the integral part
     ///
     /// ```
@@ -132,301 +259,4086 @@ impl<T: Number, const P: u32> FixedDec<T, P> {
         ten_power::<T>(P).and_then(|prec| t.checked_mul(prec).map(Self))
     }
 
-    /// Try to change the precision of the value without changing the represented value
+    /// Return whether at least one integral digit is representable at this precision
     ///
-    /// If the demanded precision is smaller than the original precision, then silent truncating will happens:
+    /// When `10^P` doesn't fit in T (e.g. `FixedDec<u8, 3>` where 10^3 > 255), no integral
+    /// value except zero is representable, and [`Self::new`] panics.
     ///
     /// ```
     /// use fixeddec::FixedDec;
-    /// let orig_value = FixedDec::<u32, 3>::new(123);
-    /// let new_value = orig_value.set_precision::<2>();
-    /// assert_eq!(new_value, Some(FixedDec::<u32, 2>::new(12)));
+    /// assert!(FixedDec::<u8, 2>::has_integral_range());
+    /// assert!(!FixedDec::<u8, 3>::has_integral_range());
     /// ```
+    pub const fn has_integral_range() -> bool {
+        T::TEN_POWER.len() > P as usize
+    }
+
+    /// Return the largest integral value representable at this precision
+    ///
+    /// Any integral value up to this one can be converted with [`Self::from_integral`]
     ///
     /// ```
     /// use fixeddec::FixedDec;
-    /// let orig_value = FixedDec::<u32, 3>::new(123);
-    /// let new_value = orig_value.set_precision::<5>();
-    /// assert_eq!(new_value, Some(FixedDec::<u32, 5>::new(12300)));
+    /// assert_eq!(FixedDec::<u8, 2>::max_integral(), 2);
+    /// assert!(FixedDec::<u8, 2>::from_integral(2).is_some());
+    /// assert!(FixedDec::<u8, 2>::from_integral(3).is_none());
     /// ```
-    ///
-    pub fn set_precision<const O: u32>(self) -> Option<FixedDec<T, O>> {
-        use core::cmp::Ordering;
-        match P.cmp(&O) {
-            Ordering::Equal => Some(FixedDec(self.0)),
-            Ordering::Greater => {
-                let diff = P - O;
-                ten_power::<T>(diff).and_then(|prec| self.0.checked_div(prec).map(FixedDec))
-            }
-            Ordering::Less => {
-                let diff = O - P;
-                ten_power::<T>(diff).and_then(|prec| self.0.checked_mul(prec).map(FixedDec))
-            }
-        }
+    pub fn max_integral() -> T {
+        T::MAX / Self::scale_factor()
     }
 
-    /// Try to convert the backing type of `FixedDec` from `T` to `U`
-    pub fn try_into<U: Number>(self) -> Result<FixedDec<U, P>, <U as TryFrom<T>>::Error>
-    where
-        U: TryFrom<T>,
-    {
-        U::try_from(self.0).map(FixedDec)
+    /// Return the smallest integral value representable at this precision
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert_eq!(FixedDec::<i8, 1>::min_integral(), -12);
+    /// assert_eq!(FixedDec::<u32, 3>::min_integral(), 0);
+    /// ```
+    pub fn min_integral() -> T {
+        T::MIN / Self::scale_factor()
     }
 
-    /// Add two elements with checked result
+    /// Create a new FixedDec approximating the ratio `num / den`
     ///
-    /// If the addition result doesn't fits in the type T, then None is returned
-    pub fn checked_add(self, rhs: Self) -> Option<Self> {
-        self.0.checked_add(rhs.0).map(Self)
+    /// The value is computed as `num * 10^P / den` in the wider backing type `T::Wide`,
+    /// and the digits after P are rounded according to `mode`.
+    ///
+    /// ```
+    /// use fixeddec::{FixedDec, RoundingMode};
+    /// let third = FixedDec::<u64, 4>::from_ratio(1, 3, RoundingMode::HalfUp).unwrap();
+    /// assert_eq!(third.value(), 3333);
+    /// let two_third = FixedDec::<u64, 4>::from_ratio(2, 3, RoundingMode::HalfUp).unwrap();
+    /// assert_eq!(two_third.value(), 6667);
+    /// ```
+    ///
+    /// If the denominator is zero or the result overflow the backing type, returns None
+    pub fn from_ratio(num: T, den: T, mode: RoundingMode) -> Option<Self> {
+        let scaled = num.to_wide().checked_mul(ten_power(P)?)?;
+        let q = rounding::div_round(scaled, den.to_wide(), mode)?;
+        T::try_from_wide(q).map(Self)
     }
 
-    /// Subtract two elements with checked result
+    /// Create a new FixedDec from a float, rounded to the nearest value at precision P
     ///
-    /// If the subtraction result doesn't fits in the type T, then None is returned
-    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
-        self.0.checked_sub(rhs.0).map(Self)
+    /// See [`Self::from_f64_checked`] for the details of the conversion.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert_eq!(FixedDec::<u32, 2>::from_f64(1.25), Some(FixedDec::new(125)));
+    /// ```
+    pub fn from_f64(value: f64) -> Option<Self> {
+        Self::from_f64_checked(value).map(|(v, _)| v)
     }
 
-    /// Multiplication (Scaling) with checked result
+    /// Create a FixedDec from a f64, clamping the out of range values to the bounds of the type
     ///
-    /// Note that operands are mixed between FixedDec and a scalar T, not another FixedDec.
+    /// Similar to [`Self::from_f64`], but a value too big (including `+inf`) gives MAX, a value
+    /// too small (including `-inf`, or any negative value for unsigned types) gives MIN,
+    /// and NaN gives zero.
     ///
-    /// If the multiplication result doesn't fits in the type T, then None is returned
-    pub fn checked_mul(self, rhs: T) -> Option<Self> {
-        self.0.checked_mul(rhs).map(Self)
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert_eq!(FixedDec::<u8, 1>::saturating_from_f64(1.25), FixedDec::new(13));
+    /// assert_eq!(FixedDec::<u8, 1>::saturating_from_f64(300.0), FixedDec::MAX);
+    /// assert_eq!(FixedDec::<u8, 1>::saturating_from_f64(f64::NAN), FixedDec::new(0));
+    /// ```
+    pub fn saturating_from_f64(value: f64) -> Self {
+        if value.is_nan() {
+            Self(T::ZERO)
+        } else {
+            Self::from_f64(value).unwrap_or(if value < 0.0 { Self::MIN } else { Self::MAX })
+        }
     }
 
-    /// Division (Inverse Scaling) with checked result
-    pub fn checked_div(self, rhs: T) -> Option<Self> {
-        self.0.checked_div(rhs).map(Self)
-    }
+    /// Create a new FixedDec from a float, also returning whether rounding occurred
+    ///
+    /// The boolean is true when the stored value differs from the exact value of the float,
+    /// for example `0.1` is not exactly representable as a float, and so the conversion
+    /// of the float `0.1` at any precision is rounded.
+    ///
+    /// When no rounding occurs, the conversion is computed exactly with integers, otherwise
+    /// the value is scaled with float arithmetic and rounded to the nearest (half away from zero).
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert_eq!(FixedDec::<u32, 2>::from_f64_checked(0.5), Some((FixedDec::new(50), false)));
+    /// assert_eq!(FixedDec::<u32, 2>::from_f64_checked(0.1), Some((FixedDec::new(10), true)));
+    /// ```
+    ///
+    /// If the float is NaN or infinite, or the value doesn't fit in T, returns None
+    pub fn from_f64_checked(value: f64) -> Option<(Self, bool)> {
+        if !value.is_finite() {
+            return None;
+        }
 
-    /// Checked remainder. Computes self % rhs, returning None if rhs == 0.
-    pub fn checked_rem(self, rhs: T) -> Option<Self> {
-        self.0.checked_rem(rhs).map(Self)
-    }
+        // decompose the float in value = mantissa * 2^exponent
+        let bits = value.to_bits();
+        let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+        let fraction = bits & ((1 << 52) - 1);
+        let (mantissa, exponent) = if biased_exponent == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | (1 << 52), biased_exponent - 1075)
+        };
+        if mantissa == 0 {
+            return Some((Self(T::ZERO), false));
+        }
+        let tz = mantissa.trailing_zeros();
+        let (mantissa, exponent) = (mantissa >> tz, exponent + tz as i32);
 
-    /// Round at a specific precision
-    pub fn round_at(self, prec: u32) -> Self {
-        if prec >= P {
-            self
+        // with an odd mantissa, the raw value mantissa * 5^P * 2^(P + exponent) is an integer
+        // only if (P + exponent) is positive, in which case it's computed exactly
+        let shift = P as i32 + exponent;
+        if shift >= 0 {
+            let two = T::ONE + T::ONE;
+            let five = ten_power::<T>(1)? / two;
+            let signed_mantissa = if value < 0.0 {
+                -(mantissa as f64)
+            } else {
+                mantissa as f64
+            };
+            let mut raw = T::from_f64_trunc(signed_mantissa)?;
+            for _ in 0..P {
+                raw = raw.checked_mul(five)?;
+            }
+            for _ in 0..shift {
+                raw = raw.checked_mul(two)?;
+            }
+            Some((Self(raw), false))
         } else {
-            // both the unwrap should not be possible to trigger since prec < P
-            // will result in a valid ten's encoding and checked_rem.
-            let wrap = ten_power::<T>(P - prec).unwrap();
-            Self(self.0 - self.0.checked_rem(wrap).unwrap())
+            let scaled = value * ten_power::<T>(P)?.to_f64();
+            let truncated = T::from_f64_trunc(scaled)?;
+            let diff = scaled - truncated.to_f64();
+            let raw = if diff >= 0.5 {
+                truncated.checked_add(T::ONE)?
+            } else if diff <= -0.5 {
+                truncated.checked_sub(T::ONE)?
+            } else {
+                truncated
+            };
+            Some((Self(raw), true))
         }
     }
 
-    /// Return the integral part of this decimal
+    /// Convert to a float, which is lossy for most values
+    ///
+    /// The raw value and the scale factor are both converted to f64 before the division,
+    /// so the result is within a few units in the last place of the exact value.
+    /// See [`Self::to_f64_checked`] for the precision loss.
     ///
     /// ```
     /// use fixeddec::FixedDec;
-    /// let f = FixedDec::<u32, 3>::new(1_234);
-    /// assert_eq!(f.integral(), 1);
+    /// assert_eq!(FixedDec::<i32, 2>::new(-125).to_f64(), -1.25);
     /// ```
-    pub fn integral(self) -> T {
-        ten_power::<T>(P)
-            .and_then(|prec| self.0.checked_div(prec))
-            .unwrap()
+    pub fn to_f64(self) -> f64 {
+        self.0.to_f64() / Self::scale_factor().to_f64()
     }
 
-    /// Return the fractional part of this decimal
+    /// Convert to a float, returning None if the value isn't finitely representable
+    ///
+    /// An f64 has 53 bits of mantissa, so raw values with a magnitude bigger than 2^53
+    /// (around 9e15) lose their least significant digits, and any fractional value not
+    /// a sum of powers of two (e.g. `0.1`) is not exactly representable.
+    ///
+    /// All the builtin integer types are in the range of f64, so this only returns None
+    /// for a backing type implementing [`Number`] with bigger values.
     ///
     /// ```
     /// use fixeddec::FixedDec;
-    /// let f = FixedDec::<u32, 3>::new(1_234);
-    /// assert_eq!(f.fractional(), 234);
+    /// let huge = FixedDec::<u128, 0>::MAX;
+    /// assert_eq!(huge.to_f64_checked(), Some(3.402823669209385e38));
     /// ```
-    pub fn fractional(self) -> T {
-        ten_power::<T>(P)
-            .and_then(|prec| self.0.checked_rem(prec))
-            .unwrap()
+    pub fn to_f64_checked(self) -> Option<f64> {
+        Some(self.to_f64()).filter(|f| f.is_finite())
     }
 
-    /// Return the content value at the precision required
+    /// Create a new FixedDec from the integral part and the fractional part at precision P
+    ///
+    /// The value is computed as `integral * 10^P + fractional`, and the fractional part
+    /// need to be strictly smaller than `10^P` in magnitude. For negative values, the
+    /// fractional part carries the sign (as returned by [`Self::into_parts`]), and
+    /// a fractional part of the opposite sign of the integral part is rejected.
     ///
     /// ```
     /// use fixeddec::FixedDec;
-    /// let f = FixedDec::<u32, 3>::new(1_234);
+    /// let f = FixedDec::<u32, 2>::from_parts(12, 34).unwrap();
     /// assert_eq!(f.value(), 1_234);
+    /// assert_eq!(FixedDec::<u32, 2>::from_parts(12, 100), None);
     /// ```
-    pub const fn value(self) -> T {
-        self.0
+    ///
+    /// If the value overflow the backing type, returns None
+    pub fn from_parts(integral: T, fractional: T) -> Option<Self> {
+        let prec = ten_power::<T>(P)?;
+        let in_range = if fractional < T::ZERO {
+            integral <= T::ZERO && T::ZERO.checked_sub(prec)? < fractional
+        } else {
+            (integral >= T::ZERO || fractional == T::ZERO) && fractional < prec
+        };
+        if !in_range {
+            return None;
+        }
+        integral
+            .checked_mul(prec)?
+            .checked_add(fractional)
+            .map(Self)
     }
 
-    /// Parse a string containing a fractional number (e.g. "1.234")
+    /// Create a new FixedDec from the integral part and a fractional part of `frac_digits` digits
     ///
-    /// If the string doesn't contain any dot, then it interpreted as an integral number.
-    pub fn from_str(s: &str) -> Option<Self> {
-        let ten = ten_power(1).unwrap(); // safe all types have 10
-        if let Some((i1, f1)) = s.split_once('.') {
-            if !i1.chars().all(|c| c.is_ascii_digit()) {
-                return None;
-            }
-            if !f1.chars().all(|c| c.is_ascii_digit()) {
-                return None;
-            }
-
-            let mut acc = T::ZERO;
-
-            // integral part
-            for c in i1.chars() {
-                let i = T::from_digit10(c)?;
-                acc = acc.checked_mul(ten)?.checked_add(i)?;
-            }
-
-            // fractional part
-            let sz_frac = f1.chars().count();
-            for (depth, c) in f1.chars().enumerate() {
-                if depth >= P as usize {
-                    break;
-                }
-                let i = T::from_digit10(c)?;
-                acc = acc.checked_mul(ten)?.checked_add(i)?;
-            }
-
-            if sz_frac < P as usize {
-                let mul = ten_power(P - sz_frac as u32)?;
-                acc = acc.checked_mul(mul)?;
-            }
-
-            Some(Self::new(acc))
-        } else {
-            // no fractional .
-            if !s.chars().all(|c| c.is_ascii_digit()) {
+    /// Similar to [`Self::from_parts`], but the fractional part is given at the precision
+    /// `frac_digits` (e.g. `(12, 5, 1)` is `12.5`), and is scaled to the precision P, by
+    /// padding with zeros if `frac_digits < P`, or by truncating the extra digits if
+    /// `frac_digits > P`. The fractional part need to be strictly smaller than `10^frac_digits`
+    /// in magnitude, and carries the sign for negative values: a nonzero fractional part with
+    /// the opposite sign of a nonzero integral part is rejected, even if it is truncated to zero.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert_eq!(FixedDec::<u32, 3>::from_parts_scaled(12, 5, 1), Some(FixedDec::new(12_500)));
+    /// assert_eq!(FixedDec::<u32, 1>::from_parts_scaled(12, 567, 3), Some(FixedDec::new(125)));
+    /// assert_eq!(FixedDec::<u32, 3>::from_parts_scaled(12, 50, 1), None);
+    /// ```
+    ///
+    /// If the value overflow the backing type, returns None
+    pub fn from_parts_scaled(integral: T, fractional: T, frac_digits: u32) -> Option<Self> {
+        // checked before the scaling, so that truncating the fractional part to zero
+        // doesn't hide a sign mismatch
+        if (fractional < T::ZERO && integral > T::ZERO)
+            || (fractional > T::ZERO && integral < T::ZERO)
+        {
+            return None;
+        }
+        // without the power of ten in T, all the values of T have less than frac_digits digits
+        if let Some(limit) = ten_power::<T>(frac_digits) {
+            let in_range = if fractional < T::ZERO {
+                T::ZERO.checked_sub(limit)? < fractional
+            } else {
+                fractional < limit
+            };
+            if !in_range {
                 return None;
             }
-            let mut acc = T::ZERO;
-            for c in s.chars() {
-                let i = T::from_digit10(c)?;
-                acc = acc.checked_mul(ten)?.checked_add(i)?;
-            }
-            Self::from_integral(acc)
         }
+        let fractional = if frac_digits <= P {
+            fractional.checked_mul(ten_power(P - frac_digits)?)?
+        } else {
+            ten_power::<T>(frac_digits - P).map_or(T::ZERO, |scale| fractional / scale)
+        };
+        Self::from_parts(integral, fractional)
     }
-}
-
-impl<T: Number, const P: u32> Add for FixedDec<T, P> {
-    type Output = Self;
-
-    fn add(self, rhs: Self) -> Self::Output {
-        Self(self.0 + rhs.0)
-    }
-}
 
-impl<T: Number, const P: u32> AddAssign for FixedDec<T, P> {
-    fn add_assign(&mut self, rhs: Self) {
-        self.0 += rhs.0;
+    /// Create a new FixedDec from a number of basis points, where 10000 bps is 1
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert_eq!(FixedDec::<u32, 4>::from_basis_points(50), Some(FixedDec::new(50)));
+    /// assert_eq!(FixedDec::<u32, 6>::from_basis_points(50), Some(FixedDec::new(5_000)));
+    /// assert_eq!(FixedDec::<u32, 2>::from_basis_points(2_500), Some(FixedDec::new(25)));
+    /// assert_eq!(FixedDec::<u32, 2>::from_basis_points(250), None);
+    /// ```
+    ///
+    /// If the value overflow the backing type, or is not representable exactly at the
+    /// precision P (when P < 4), returns None
+    pub fn from_basis_points(bps: T) -> Option<Self> {
+        let raw = if P >= 4 {
+            bps.checked_mul(ten_power(P - 4)?)?
+        } else if bps == T::ZERO {
+            T::ZERO
+        } else {
+            let scale = ten_power::<T>(4 - P)?;
+            (bps.checked_rem(scale)? == T::ZERO).then(|| bps / scale)?
+        };
+        Self::new_checked(raw)
     }
-}
 
-impl<T: Number, const P: u32> Sub for FixedDec<T, P> {
-    type Output = Self;
-
-    fn sub(self, rhs: Self) -> Self::Output {
-        Self(self.0 - rhs.0)
+    /// Return the value as a number of basis points, where 1 is 10000 bps
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert_eq!(FixedDec::<u32, 4>::new(50).to_basis_points(), Some(50));
+    /// assert_eq!(FixedDec::<u32, 2>::new(25).to_basis_points(), Some(2_500));
+    /// assert_eq!(FixedDec::<u32, 6>::new(5_001).to_basis_points(), None);
+    /// ```
+    ///
+    /// If the number of basis points overflow the backing type, or is not a whole number
+    /// (when P > 4), returns None
+    pub fn to_basis_points(self) -> Option<T> {
+        if P <= 4 {
+            if self.0 == T::ZERO {
+                return Some(T::ZERO);
+            }
+            self.0.checked_mul(ten_power(4 - P)?)
+        } else {
+            let scale = ten_power::<T>(P - 4)?;
+            (self.0.checked_rem(scale)? == T::ZERO).then(|| self.0 / scale)
+        }
     }
-}
 
-impl<T: Number, const P: u32> SubAssign for FixedDec<T, P> {
-    fn sub_assign(&mut self, rhs: Self) {
-        self.0 -= rhs.0
+    /// Split the value into its integral part and its fractional part at precision P
+    ///
+    /// Both parts carry the sign of the value, so that [`Self::from_parts`] gives back
+    /// the original value. Note that this differs from [`Self::fractional`] which
+    /// always return the magnitude of the fractional part.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert_eq!(FixedDec::<u32, 2>::new(1_234).into_parts(), (12, 34));
+    /// assert_eq!(FixedDec::<i32, 2>::new(-1_234).into_parts(), (-12, -34));
+    /// ```
+    ///
+    /// Panics if the precision P is too big for T
+    pub fn into_parts(self) -> (T, T) {
+        let prec = ten_power::<T>(P).unwrap();
+        let integral = self.0 / prec;
+        (integral, self.0 - integral * prec)
     }
-}
 
-impl<T: Number, const P: u32> Mul<T> for FixedDec<T, P> {
-    type Output = Self;
-
-    fn mul(self, rhs: T) -> Self::Output {
-        Self(self.0 * rhs)
+    /// Return the exact rational represented by the value, as a reduced numerator and denominator
+    ///
+    /// The ratio is `value / 10^P` divided by the greatest common divisor of both terms,
+    /// so the denominator is always positive and the numerator carries the sign.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert_eq!(FixedDec::<u32, 2>::new(150).to_ratio(), Some((3, 2)));
+    /// assert_eq!(FixedDec::<i32, 3>::new(-125).to_ratio(), Some((-1, 8)));
+    /// ```
+    ///
+    /// If the precision P is too big for T (e.g. a value created by [`Self::try_into`]),
+    /// the denominator `10^P` doesn't fit in T and None is returned
+    pub fn to_ratio(self) -> Option<(T, T)> {
+        let den = ten_power::<T>(P)?;
+        let g = number::gcd(den, self.0);
+        // the gcd divides 10^P, so its magnitude fits in T
+        let g = if g < T::ZERO { T::ZERO - g } else { g };
+        Some((self.0 / g, den / g))
     }
-}
 
-impl<T: Number, const P: u32> MulAssign<T> for FixedDec<T, P> {
-    fn mul_assign(&mut self, rhs: T) {
-        self.0 *= rhs
-    }
+    /// Try to change the precision of the value without changing the represented value
+    ///
+    /// If the demanded precision is smaller than the original precision, then silent truncating will happens:
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// let orig_value = FixedDec::<u32, 3>::new(123);
+    /// let new_value = orig_value.set_precision::<2>();
+    /// assert_eq!(new_value, Some(FixedDec::<u32, 2>::new(12)));
+    /// ```
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// let orig_value = FixedDec::<u32, 3>::new(123);
+    /// let new_value = orig_value.set_precision::<5>();
+    /// assert_eq!(new_value, Some(FixedDec::<u32, 5>::new(12300)));
+    /// ```
+    ///
+    pub fn set_precision<const O: u32>(self) -> Option<FixedDec<T, O>> {
+        use core::cmp::Ordering;
+        match P.cmp(&O) {
+            Ordering::Equal => Some(FixedDec(self.0)),
+            Ordering::Greater => {
+                let diff = P - O;
+                ten_power::<T>(diff).and_then(|prec| self.0.checked_div(prec).map(FixedDec))
+            }
+            Ordering::Less => {
+                let diff = O - P;
+                ten_power::<T>(diff).and_then(|prec| self.0.checked_mul(prec).map(FixedDec))
+            }
+        }
+    }
+
+    /// Change the precision of the value, clamping to the bounds of the type on overflow
+    ///
+    /// Similar to [`Self::set_precision`], with silent truncating when decreasing the precision,
+    /// but when increasing the precision overflow the backing type, the value is clamped
+    /// to MAX (or MIN for negative values) instead of returning None.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// let orig_value = FixedDec::<u8, 0>::new(3);
+    /// assert_eq!(orig_value.set_precision_saturating::<2>(), FixedDec::<u8, 2>::MAX);
+    /// ```
+    pub fn set_precision_saturating<const O: u32>(self) -> FixedDec<T, O> {
+        self.set_precision().unwrap_or({
+            if self.0 < T::ZERO {
+                FixedDec::MIN
+            } else {
+                FixedDec::MAX
+            }
+        })
+    }
+
+    /// Change the precision of the value to 3 digits, the SI milli unit
+    ///
+    /// Similar to [`Self::set_precision`]: the extra digits are truncated when
+    /// decreasing the precision, and None is returned on overflow of the backing type.
+    pub fn to_milli(self) -> Option<FixedDec<T, 3>> {
+        self.set_precision()
+    }
+
+    /// Change the precision of the value to 6 digits, the SI micro unit
+    ///
+    /// Similar to [`Self::set_precision`]: the extra digits are truncated when
+    /// decreasing the precision, and None is returned on overflow of the backing type.
+    pub fn to_micro(self) -> Option<FixedDec<T, 6>> {
+        self.set_precision()
+    }
+
+    /// Change the precision of the value to 9 digits, the SI nano unit
+    ///
+    /// Similar to [`Self::set_precision`]: the extra digits are truncated when
+    /// decreasing the precision, and None is returned on overflow of the backing type.
+    pub fn to_nano(self) -> Option<FixedDec<T, 9>> {
+        self.set_precision()
+    }
+
+    /// Try to increase the precision of the value, without ever losing any digits
+    ///
+    /// Contrary to [`Self::set_precision`], a demanded precision smaller than the original
+    /// precision is rejected and returns None, as is a value that overflow the backing type
+    /// at the new precision.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// let orig_value = FixedDec::<u32, 3>::new(123);
+    /// assert_eq!(orig_value.widen_precision::<5>(), Some(FixedDec::<u32, 5>::new(12300)));
+    /// assert_eq!(orig_value.widen_precision::<2>(), None);
+    /// ```
+    pub fn widen_precision<const O: u32>(self) -> Option<FixedDec<T, O>> {
+        if O < P { None } else { self.set_precision() }
+    }
+
+    /// Try to decrease the precision of the value, rounding the dropped digits according to `mode`
+    ///
+    /// Contrary to [`Self::set_precision`] which always truncates, the rounding is explicit.
+    /// A demanded precision bigger than the original precision is rejected and returns None,
+    /// as is a rounding that overflow the backing type.
+    ///
+    /// ```
+    /// use fixeddec::{FixedDec, RoundingMode};
+    /// let orig_value = FixedDec::<u32, 3>::new(125);
+    /// let rounded = orig_value.narrow_precision_with::<2>(RoundingMode::HalfEven);
+    /// assert_eq!(rounded, Some(FixedDec::<u32, 2>::new(12)));
+    /// ```
+    pub fn narrow_precision_with<const O: u32>(self, mode: RoundingMode) -> Option<FixedDec<T, O>> {
+        if O > P {
+            None
+        } else {
+            ten_power::<T>(P - O)
+                .and_then(|prec| rounding::div_round(self.0, prec, mode))
+                .map(FixedDec)
+        }
+    }
+
+    /// Change the precision of the value, failing instead of dropping any nonzero digits
+    ///
+    /// This is the strict version of [`Self::set_precision`]: when decreasing the precision,
+    /// None is returned if the dropped digits are not all zeros, and when increasing the
+    /// precision, None is returned if the value overflow the backing type.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert_eq!(FixedDec::<u32, 3>::new(1200).set_precision_exact::<1>(), Some(FixedDec::new(12)));
+    /// assert_eq!(FixedDec::<u32, 3>::new(1234).set_precision_exact::<1>(), None);
+    /// ```
+    pub fn set_precision_exact<const O: u32>(self) -> Option<FixedDec<T, O>> {
+        self.set_precision::<O>()
+            .filter(|changed| changed.eq_value(self))
+    }
+
+    /// Return whether the value can be represented at precision O without losing any digits
+    ///
+    /// This is true when [`Self::set_precision_exact`] succeeds, so when [`Self::set_precision`]
+    /// would neither drop nonzero digits when decreasing the precision, nor overflow the
+    /// backing type when increasing the precision.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert!(FixedDec::<u32, 3>::new(1200).fits_precision::<1>());
+    /// assert!(!FixedDec::<u32, 3>::new(1234).fits_precision::<1>());
+    /// ```
+    pub fn fits_precision<const O: u32>(self) -> bool {
+        self.set_precision_exact::<O>().is_some()
+    }
+
+    /// Return the raw values of self and other, scaled in `T::Wide` to the biggest of both precisions
+    fn scaled_pair<const Q: u32>(self, other: FixedDec<T, Q>) -> Option<(T::Wide, T::Wide)> {
+        let a = self.0.to_wide();
+        let b = other.0.to_wide();
+        if P >= Q {
+            Some((a, b.checked_mul(ten_power(P - Q)?)?))
+        } else {
+            Some((a.checked_mul(ten_power(Q - P)?)?, b))
+        }
+    }
+
+    /// Compare the represented values of two FixedDec of different precisions
+    ///
+    /// Both values are scaled to the biggest precision in the wider backing type `T::Wide`
+    /// before being compared, and None is returned only if this scaling overflow.
+    ///
+    /// ```
+    /// use core::cmp::Ordering;
+    /// use fixeddec::FixedDec;
+    /// let a = FixedDec::<u32, 1>::new(15);
+    /// let b = FixedDec::<u32, 2>::new(150);
+    /// assert_eq!(a.cmp_scaled(b), Some(Ordering::Equal));
+    /// ```
+    pub fn cmp_scaled<const Q: u32>(self, other: FixedDec<T, Q>) -> Option<core::cmp::Ordering> {
+        self.scaled_pair(other).map(|(a, b)| a.cmp(&b))
+    }
+
+    /// Compare the value with another FixedDec of the same precision but another backing type U
+    ///
+    /// The comparison is done in U if self fits in U, otherwise in T if other fits in T.
+    ///
+    /// ```
+    /// use core::cmp::Ordering;
+    /// use fixeddec::FixedDec;
+    /// let a = FixedDec::<u32, 2>::new(150);
+    /// assert_eq!(a.cmp_as(FixedDec::<u64, 2>::new(150)), Some(Ordering::Equal));
+    /// assert_eq!(a.cmp_as(FixedDec::<u64, 2>::MAX), Some(Ordering::Less));
+    /// ```
+    ///
+    /// If neither value can be converted to the other type, returns None
+    pub fn cmp_as<U>(self, other: FixedDec<U, P>) -> Option<core::cmp::Ordering>
+    where
+        U: Number + TryFrom<T>,
+        T: TryFrom<U>,
+    {
+        match U::try_from(self.0) {
+            Ok(a) => Some(a.cmp(&other.0)),
+            Err(_) => T::try_from(other.0).ok().map(|b| self.0.cmp(&b)),
+        }
+    }
+
+    /// Return whether two FixedDec of different precisions represent the same value
+    ///
+    /// This differs from the derived `PartialEq`, which requires both operands to have the same
+    /// type and compares the raw values. Here `1.5` at P=1 is equal to `1.500` at P=3.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// let a = FixedDec::<u32, 1>::new(15);
+    /// assert!(a.eq_value(FixedDec::<u32, 3>::new(1500)));
+    /// assert!(!a.eq_value(FixedDec::<u32, 3>::new(1501)));
+    /// ```
+    pub fn eq_value<const Q: u32>(self, other: FixedDec<T, Q>) -> bool {
+        // if the scaling overflow, the scaled value is out of the range of the other one
+        self.cmp_scaled(other) == Some(core::cmp::Ordering::Equal)
+    }
+
+    /// Try to convert the backing type of `FixedDec` from `T` to `U`
+    ///
+    /// When U can represent all the values of T (e.g. `u8` to `u32`), the infallible
+    /// `From` conversion is also available, and the other pairs of builtin types
+    /// implement `TryFrom`, which also checks that the precision P is valid for U:
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// let f = FixedDec::<u8, 2>::new(250);
+    /// assert_eq!(f.try_into::<i8>().ok(), None);
+    /// assert!(FixedDec::<i8, 2>::try_from(f).is_err());
+    /// assert_eq!(FixedDec::<u32, 2>::from(f), FixedDec::new(250));
+    /// ```
+    pub fn try_into<U>(self) -> Result<FixedDec<U, P>, <U as TryFrom<T>>::Error>
+    where
+        U: Number + TryFrom<T>,
+    {
+        U::try_from(self.0).map(FixedDec)
+    }
+
+    /// Try to convert the backing type of `FixedDec` from `T` to `U`, changing the precision to O
+    ///
+    /// When decreasing the precision, the precision is reduced first by rounding according to `mode`,
+    /// and the backing type is converted after, which maximize the chance of the value to fit in U.
+    /// When increasing the precision, the backing type is converted first, and the precision
+    /// is increased after, in the type U.
+    ///
+    /// ```
+    /// use fixeddec::{FixedDec, RoundingMode};
+    /// let f = FixedDec::<u64, 6>::new(123_456_789);
+    /// assert!(f.try_into::<u16>().is_err());
+    /// let r = f.try_into_rounded::<u16, 2>(RoundingMode::HalfUp);
+    /// assert_eq!(r, Some(FixedDec::<u16, 2>::new(12_346)));
+    /// ```
+    ///
+    /// If the value doesn't fit in U at precision O, returns None
+    pub fn try_into_rounded<U, const O: u32>(self, mode: RoundingMode) -> Option<FixedDec<U, O>>
+    where
+        U: Number + TryFrom<T>,
+    {
+        // the precision need to be valid for the target type
+        ten_power::<U>(O)?;
+        if O <= P {
+            self.narrow_precision_with::<O>(mode)?.try_into().ok()
+        } else {
+            self.try_into::<U>().ok()?.widen_precision()
+        }
+    }
+
+    /// Add two elements with checked result
+    ///
+    /// If the addition result doesn't fits in the type T, then None is returned
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.0.checked_add(rhs.0).map(Self)
+    }
+
+    /// Add a FixedDec of another precision, returning the sum at precision O
+    ///
+    /// Both operands are promoted to the biggest of their precisions in the wider backing
+    /// type `T::Wide` before the addition, so no digit is lost. The result precision O need
+    /// to be at least the biggest of P and Q, and is usually inferred from the context.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// let price = FixedDec::<u32, 2>::new(125);
+    /// let fee = FixedDec::<u32, 4>::new(75);
+    /// let total: FixedDec<u32, 4> = price.add_scaled(fee).unwrap();
+    /// assert_eq!(total, FixedDec::new(12575));
+    /// ```
+    ///
+    /// If O is smaller than P or Q, or the sum doesn't fit in T at precision O, returns None
+    pub fn add_scaled<const Q: u32, const O: u32>(
+        self,
+        other: FixedDec<T, Q>,
+    ) -> Option<FixedDec<T, O>> {
+        let max = P.max(Q);
+        if O < max {
+            return None;
+        }
+        let (a, b) = self.scaled_pair(other)?;
+        let sum = a.checked_add(b)?.checked_mul(ten_power(O - max)?)?;
+        T::try_from_wide(sum).map(FixedDec)
+    }
+
+    /// Add all the elements of a slice with checked result
+    ///
+    /// The sum of an empty slice is zero.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// let items = [FixedDec::<u32, 2>::new(150), FixedDec::new(25), FixedDec::new(1)];
+    /// assert_eq!(FixedDec::checked_sum(&items), Some(FixedDec::new(176)));
+    /// ```
+    ///
+    /// If any intermediate result doesn't fits in the type T, then None is returned
+    pub fn checked_sum(items: &[Self]) -> Option<Self> {
+        items
+            .iter()
+            .try_fold(Self(T::ZERO), |acc, x| acc.checked_add(*x))
+    }
+
+    /// Dot product of the values by scalar weights, `sum(values[i] * weights[i])`
+    ///
+    /// The products and the sum are computed in the wider backing type `T::Wide`,
+    /// so only the final result need to fit in T.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// let prices = [FixedDec::<u32, 2>::new(1050), FixedDec::new(299)];
+    /// let quantities = [3, 10];
+    /// assert_eq!(FixedDec::weighted_sum(&prices, &quantities), Some(FixedDec::new(6140)));
+    /// ```
+    ///
+    /// If the slices have different lengths, or the result doesn't fits in T, then None is returned
+    pub fn weighted_sum(values: &[Self], weights: &[T]) -> Option<Self> {
+        if values.len() != weights.len() {
+            return None;
+        }
+        let sum = values
+            .iter()
+            .zip(weights)
+            .try_fold(T::Wide::ZERO, |acc, (v, w)| {
+                acc.checked_add(v.0.to_wide().checked_mul(w.to_wide())?)
+            })?;
+        T::try_from_wide(sum).map(Self)
+    }
+
+    /// Subtract two elements with checked result
+    ///
+    /// If the subtraction result doesn't fits in the type T, then None is returned
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.0.checked_sub(rhs.0).map(Self)
+    }
+
+    /// Subtract two elements into the signed counterpart of T, so that the result can be negative
+    ///
+    /// For unsigned types, where `rhs > self` makes the subtraction fail, the difference
+    /// is computed in `T::Signed` instead. For signed types this is the same as `checked_sub`.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// let a = FixedDec::<u32, 2>::new(200);
+    /// let b = FixedDec::<u32, 2>::new(500);
+    /// assert_eq!(a.checked_sub(b), None);
+    /// assert_eq!(a.sub_signed(b), Some(FixedDec::<i32, 2>::new(-300)));
+    /// ```
+    ///
+    /// If the result doesn't fits in `T::Signed`, or P is not a valid precision
+    /// for `T::Signed`, then None is returned
+    pub fn sub_signed(self, rhs: Self) -> Option<FixedDec<T::Signed, P>> {
+        let v = if self.0 >= rhs.0 {
+            self.0.checked_sub(rhs.0)?.try_to_signed()?
+        } else {
+            // negate the difference as -(d - 1) - 1, which reaches the signed MIN
+            let d = rhs.0.checked_sub(self.0)?;
+            let d = (d - T::ONE).try_to_signed()?;
+            T::Signed::ZERO
+                .checked_sub(d)?
+                .checked_sub(T::Signed::ONE)?
+        };
+        FixedDec::new_checked(v)
+    }
+
+    /// Add an integral amount `n` (e.g. 5 meaning `5.000` at P = 3) with checked result
+    ///
+    /// The scaling of `n` and the addition are computed in the wider backing type, so that
+    /// only a result outside of the range of T fails.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// let f = FixedDec::<u32, 3>::new(1_250);
+    /// assert_eq!(f.checked_add_integral(5), Some(FixedDec::new(6_250)));
+    /// assert_eq!(f.checked_add_integral(5_000_000), None);
+    /// ```
+    pub fn checked_add_integral(self, n: T) -> Option<Self> {
+        let scaled = n.to_wide().checked_mul(ten_power(P)?)?;
+        T::try_from_wide(self.0.to_wide().checked_add(scaled)?).map(Self)
+    }
+
+    /// Subtract an integral amount `n` (e.g. 5 meaning `5.000` at P = 3) with checked result
+    ///
+    /// The scaling of `n` and the subtraction are computed in the wider backing type, so that
+    /// only a result outside of the range of T fails.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// let f = FixedDec::<u32, 3>::new(6_250);
+    /// assert_eq!(f.checked_sub_integral(5), Some(FixedDec::new(1_250)));
+    /// assert_eq!(f.checked_sub_integral(7), None);
+    /// ```
+    pub fn checked_sub_integral(self, n: T) -> Option<Self> {
+        let scaled = n.to_wide().checked_mul(ten_power(P)?)?;
+        T::try_from_wide(self.0.to_wide().checked_sub(scaled)?).map(Self)
+    }
+
+    /// Absolute difference between two elements, regardless of their order
+    ///
+    /// For unsigned types, this never fails, contrary to a subtraction of the biggest from the smallest.
+    /// For signed types, the difference of two values of opposite signs may not fit in the type T,
+    /// in which case None is returned
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// let a = FixedDec::<u32, 2>::new(100);
+    /// let b = FixedDec::<u32, 2>::new(250);
+    /// assert_eq!(a.abs_diff(b), Some(FixedDec::new(150)));
+    /// assert_eq!(b.abs_diff(a), Some(FixedDec::new(150)));
+    /// ```
+    pub fn abs_diff(self, other: Self) -> Option<Self> {
+        self.0.abs_diff(other.0).map(Self)
+    }
+
+    /// Number of raw units (ulps, `10^-P`) between two elements, regardless of their order
+    ///
+    /// This is the raw value of [`Self::abs_diff`], useful to check that two computed
+    /// values are within a number of ulps. For signed types, None is returned if the
+    /// difference doesn't fit in the type T.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// let a = FixedDec::<u32, 2>::new(1_000);
+    /// let b = FixedDec::<u32, 2>::new(1_003);
+    /// assert_eq!(a.ulps_between(b), Some(3));
+    /// ```
+    pub fn ulps_between(self, other: Self) -> Option<T> {
+        self.0.abs_diff(other.0)
+    }
+
+    /// Return whether two elements are equal within the tolerance `tol`, inclusive
+    ///
+    /// ```
+    /// use fixeddec::{FixedDec, RoundingMode};
+    /// let total = FixedDec::<u64, 4>::new(100_0000);
+    /// let third = total.div_round(3, RoundingMode::HalfUp).unwrap();
+    /// let tol = FixedDec::<u64, 4>::new(100); // 0.01
+    /// assert!((third * 3).approx_eq(total, tol));
+    /// assert!(!third.approx_eq(total, tol));
+    /// ```
+    pub fn approx_eq(self, other: Self, tol: Self) -> bool {
+        // a difference that doesn't fit in T is bigger than any tolerance
+        self.abs_diff(other).is_some_and(|diff| diff <= tol)
+    }
+
+    /// Midpoint between self and other, rounded toward zero at the precision P
+    ///
+    /// Contrary to `(a + b) / 2`, this never overflows, even for values near the type's bounds
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// let a = FixedDec::<u8, 1>::new(250);
+    /// let b = FixedDec::<u8, 1>::new(254);
+    /// assert_eq!(a.midpoint(b), FixedDec::new(252));
+    /// ```
+    pub fn midpoint(self, other: Self) -> Self {
+        Self(self.0.midpoint(other.0))
+    }
+
+    /// Return the next representable value, one raw unit (`10^-P`) above self
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert_eq!(FixedDec::<u32, 2>::new(150).next_up(), Some(FixedDec::new(151)));
+    /// assert_eq!(FixedDec::<u32, 2>::MAX.next_up(), None);
+    /// ```
+    pub fn next_up(self) -> Option<Self> {
+        self.0.checked_add(T::ONE).map(Self)
+    }
+
+    /// Return the previous representable value, one raw unit (`10^-P`) below self
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert_eq!(FixedDec::<u32, 2>::new(150).next_down(), Some(FixedDec::new(149)));
+    /// assert_eq!(FixedDec::<u32, 2>::MIN.next_down(), None);
+    /// ```
+    pub fn next_down(self) -> Option<Self> {
+        self.0.checked_sub(T::ONE).map(Self)
+    }
+
+    /// Linear interpolation between self and other, computing `self + (other - self) * t`
+    ///
+    /// `t` is usually a fraction between 0 and 1, with 0 giving self and 1 giving other,
+    /// and other values extrapolate. The computation is done in the wider backing type,
+    /// so it works when other is smaller than self, including for unsigned types,
+    /// and the step is truncated toward self.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// let a = FixedDec::<u32, 2>::new(1000);
+    /// let b = FixedDec::<u32, 2>::new(0);
+    /// let quarter = FixedDec::<u32, 2>::new(25);
+    /// assert_eq!(a.lerp(b, quarter), Some(FixedDec::new(750)));
+    /// assert_eq!(b.lerp(a, quarter), Some(FixedDec::new(250)));
+    /// ```
+    ///
+    /// If the result doesn't fit in T, returns None
+    pub fn lerp(self, other: Self, t: Self) -> Option<Self> {
+        let (a, b) = (self.0.to_wide(), other.0.to_wide());
+        let scale = ten_power::<T::Wide>(P)?;
+        // |other - self| * t, computed on the magnitude to stay valid for unsigned types
+        let step = |diff: T::Wide| {
+            rounding::div_round(
+                diff.checked_mul(t.0.to_wide())?,
+                scale,
+                RoundingMode::TowardZero,
+            )
+        };
+        let r = if b >= a {
+            a.checked_add(step(b.checked_sub(a)?)?)?
+        } else {
+            a.checked_sub(step(a.checked_sub(b)?)?)?
+        };
+        T::try_from_wide(r).map(Self)
+    }
+
+    /// Multiplication (Scaling) with checked result
+    ///
+    /// Note that operands are mixed between FixedDec and a scalar T, not another FixedDec.
+    ///
+    /// If the multiplication result doesn't fits in the type T, then None is returned
+    pub fn checked_mul(self, rhs: T) -> Option<Self> {
+        self.0.checked_mul(rhs).map(Self)
+    }
+
+    /// Multiplication (Scaling) with checked result, computed in the wider backing type
+    ///
+    /// The product is computed in `T::Wide`, so a product that overflows `T` but fits
+    /// in the wider type still succeeds, and can be narrowed back later (e.g. after a division).
+    ///
+    /// The widenings are:
+    ///
+    /// * `u8` -> `u16`, `u16` -> `u32`, `u32` -> `u64`, `u64` -> `u128`
+    /// * `i8` -> `i16`, `i16` -> `i32`, `i32` -> `i64`, `i64` -> `i128`
+    /// * `u128` and `i128` have no wider builtin type, and are computed in the same type
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// let f = FixedDec::<u32, 2>::new(4_000_000_000);
+    /// assert_eq!(f.checked_mul(2), None);
+    /// assert_eq!(f.checked_mul_widening(2), Some(FixedDec::<u64, 2>::new(8_000_000_000)));
+    /// ```
+    pub fn checked_mul_widening(self, rhs: T) -> Option<FixedDec<T::Wide, P>> {
+        self.0.to_wide().checked_mul(rhs.to_wide()).map(FixedDec)
+    }
+
+    /// Multiplication (Scaling) with checked result, computed in the chosen backing type U
+    ///
+    /// Contrary to [`Self::checked_mul_widening`] which always use `T::Wide`, the result type
+    /// is any type which can represent all the values of T, e.g. `u64` or `u128` for a `u32`.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// let f = FixedDec::<u32, 2>::new(4_000_000_000);
+    /// let r: Option<FixedDec<u128, 2>> = f.mul_widening(4_000_000_000);
+    /// assert_eq!(r, Some(FixedDec::new(16_000_000_000_000_000_000)));
+    /// ```
+    ///
+    /// If the multiplication result doesn't fits in the type U, then None is returned
+    pub fn mul_widening<U>(self, rhs: T) -> Option<FixedDec<U, P>>
+    where
+        U: Number + From<T>,
+    {
+        U::from(self.0).checked_mul(U::from(rhs)).map(FixedDec)
+    }
+
+    /// Division (Inverse Scaling) with checked result
+    pub fn checked_div(self, rhs: T) -> Option<Self> {
+        self.0.checked_div(rhs).map(Self)
+    }
+
+    /// Division (Inverse Scaling) with the digits after P rounded according to `mode`
+    ///
+    /// Contrary to [`Self::checked_div`] which truncates, the last digit is rounded.
+    ///
+    /// ```
+    /// use fixeddec::{FixedDec, RoundingMode};
+    /// let f = FixedDec::<u32, 2>::new(200);
+    /// assert_eq!(f.checked_div(3), Some(FixedDec::new(66)));
+    /// assert_eq!(f.div_round(3, RoundingMode::HalfUp), Some(FixedDec::new(67)));
+    /// ```
+    ///
+    /// If rhs is zero or the division overflow, then None is returned
+    pub fn div_round(self, rhs: T, mode: RoundingMode) -> Option<Self> {
+        rounding::div_round(self.0, rhs, mode).map(Self)
+    }
+
+    /// Division (Inverse Scaling) returning both the quotient and the remainder at precision P
+    ///
+    /// The quotient is truncated toward zero, and the remainder has the sign of self,
+    /// so that `quotient * rhs + remainder == self`, which allows to split an amount exactly.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// let bill = FixedDec::<u32, 2>::new(1000);
+    /// let (share, leftover) = bill.div_rem(3).unwrap();
+    /// assert_eq!(share, FixedDec::new(333));
+    /// assert_eq!(leftover, FixedDec::new(1));
+    /// ```
+    ///
+    /// If rhs is zero or the division overflow, then None is returned
+    pub fn div_rem(self, rhs: T) -> Option<(Self, Self)> {
+        let q = self.0.checked_div(rhs)?;
+        // |q * rhs| <= |self| so this cannot overflow
+        let r = self.0 - q * rhs;
+        Some((Self(q), Self(r)))
+    }
+
+    /// Split the value into `n` parts which sum exactly to the value
+    ///
+    /// Each part is the value divided by `n` truncated toward zero, and the leftover ulps
+    /// (`10^-P`) are distributed one by one to the first parts, so the parts differ by
+    /// at most one ulp.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// let bill = FixedDec::<u32, 2>::new(1000);
+    /// let parts = bill.split(3).unwrap();
+    /// assert_eq!(parts, [FixedDec::new(334), FixedDec::new(333), FixedDec::new(333)]);
+    /// ```
+    ///
+    /// If `n` is zero or doesn't fit in T, then None is returned
+    pub fn split(self, n: u32) -> Option<alloc::vec::Vec<Self>>
+    where
+        T: TryFrom<u32>,
+    {
+        let (share, leftover) = self.div_rem(T::try_from(n).ok()?)?;
+        let mut leftover = leftover.0;
+        let parts = (0..n)
+            .map(|_| {
+                // |share| + 1 <= |self| when there's a leftover, so this cannot overflow
+                if leftover > T::ZERO {
+                    leftover -= T::ONE;
+                    Self(share.0 + T::ONE)
+                } else if leftover < T::ZERO {
+                    leftover += T::ONE;
+                    Self(share.0 - T::ONE)
+                } else {
+                    share
+                }
+            })
+            .collect();
+        Some(parts)
+    }
+
+    /// Fused multiplication and division (e.g. applying a rate), computing `self * num / den`
+    ///
+    /// The multiplication is done in the wider backing type `T::Wide` before the division,
+    /// so the intermediate product doesn't overflow, and there's only one truncation of the result.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// let f = FixedDec::<u32, 2>::new(4_000_000_000);
+    /// assert_eq!(f.checked_mul(3), None);
+    /// assert_eq!(f.mul_div(3, 4), Some(FixedDec::new(3_000_000_000)));
+    /// ```
+    ///
+    /// If the denominator is zero or the result doesn't fit in the type T, then None is returned
+    pub fn mul_div(self, num: T, den: T) -> Option<Self> {
+        let q = self
+            .0
+            .to_wide()
+            .checked_mul(num.to_wide())?
+            .checked_div(den.to_wide())?;
+        T::try_from_wide(q).map(Self)
+    }
+
+    /// Multiplication of two FixedDec, with the digits after P truncated toward zero
+    ///
+    /// The product is computed in the wider backing type `T::Wide` at precision 2P, then
+    /// scaled back to P. See [`Self::mul_fixed_round`] to choose the rounding.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// let a = FixedDec::<u32, 2>::new(150);
+    /// let b = FixedDec::<u32, 2>::new(333);
+    /// assert_eq!(a.checked_mul_fixed(b), Some(FixedDec::new(499)));
+    /// ```
+    ///
+    /// If the result doesn't fit in the type T, then None is returned
+    pub fn checked_mul_fixed(self, rhs: Self) -> Option<Self> {
+        self.mul_fixed_round(rhs, RoundingMode::TowardZero)
+    }
+
+    /// Multiplication of two FixedDec, with the digits after P rounded according to `mode`
+    ///
+    /// ```
+    /// use fixeddec::{FixedDec, RoundingMode};
+    /// let a = FixedDec::<u32, 3>::new(150);
+    /// // 0.150 * 0.150 = 0.0225
+    /// assert_eq!(a.mul_fixed_round(a, RoundingMode::HalfEven), Some(FixedDec::new(22)));
+    /// assert_eq!(a.mul_fixed_round(a, RoundingMode::HalfUp), Some(FixedDec::new(23)));
+    /// ```
+    ///
+    /// If the result doesn't fit in the type T, then None is returned
+    pub fn mul_fixed_round(self, rhs: Self, mode: RoundingMode) -> Option<Self> {
+        let product = self.0.to_wide().checked_mul(rhs.0.to_wide())?;
+        let q = rounding::div_round(product, ten_power(P)?, mode)?;
+        T::try_from_wide(q).map(Self)
+    }
+
+    /// Multiplication by a rate of any precision Q, with the result at precision P
+    ///
+    /// The product is computed in the wider backing type `T::Wide` at precision P + Q,
+    /// then the Q extra digits are rounded according to `mode`.
+    ///
+    /// ```
+    /// use fixeddec::{FixedDec, RoundingMode};
+    /// let usd = FixedDec::<u64, 2>::new(100_00);
+    /// let rate = FixedDec::<u64, 4>::new(8542);
+    /// assert_eq!(usd.apply_rate(rate, RoundingMode::HalfEven), Some(FixedDec::new(85_42)));
+    /// ```
+    ///
+    /// If the result doesn't fit in the type T, then None is returned
+    pub fn apply_rate<const Q: u32>(
+        self,
+        rate: FixedDec<T, Q>,
+        mode: RoundingMode,
+    ) -> Option<Self> {
+        let product = self.0.to_wide().checked_mul(rate.0.to_wide())?;
+        let q = rounding::div_round(product, ten_power(Q)?, mode)?;
+        T::try_from_wide(q).map(Self)
+    }
+
+    /// Multiply all the values of an iterator with checked result
+    ///
+    /// Each multiplication is done like [`Self::checked_mul_fixed`], so the digits after P
+    /// are truncated after each step. The product of an empty iterator is one.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// let growth = [FixedDec::<u32, 2>::new(110), FixedDec::new(150)];
+    /// assert_eq!(FixedDec::try_product(growth), Some(FixedDec::new(165)));
+    /// ```
+    ///
+    /// If any intermediate result doesn't fit in the type T, then None is returned
+    pub fn try_product<I: IntoIterator<Item = Self>>(iter: I) -> Option<Self> {
+        iter.into_iter()
+            .try_fold(Self(Self::scale_factor()), |acc, x| {
+                acc.checked_mul_fixed(x)
+            })
+    }
+
+    /// Raise the value to an integral power, with checked result
+    ///
+    /// The power is computed by repeated squaring, where each multiplication is done in
+    /// the wider backing type `T::Wide` and immediately scaled back to precision P, so the
+    /// intermediate results don't overflow before the final result does.
+    ///
+    /// Each multiplication truncates the digits after P, losing less than one unit in the
+    /// last place, and this error is carried over by the following multiplications, so the
+    /// result can be a few units in the last place below the exact power.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// let x = FixedDec::<u64, 4>::new(15_000);
+    /// assert_eq!(x.checked_pow(3), Some(FixedDec::new(33_750)));
+    /// assert_eq!(x.checked_pow(0), Some(FixedDec::new(10_000)));
+    /// ```
+    ///
+    /// If any intermediate result doesn't fit in the type T, then None is returned
+    pub fn checked_pow(self, exp: u32) -> Option<Self> {
+        let mut result = Self(Self::scale_factor());
+        let mut base = self;
+        let mut exp = exp;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.checked_mul_fixed(base)?;
+            }
+            exp >>= 1;
+            // don't compute a square that isn't needed and could overflow
+            if exp > 0 {
+                base = base.checked_mul_fixed(base)?;
+            }
+        }
+        Some(result)
+    }
+
+    /// Checked remainder. Computes self % rhs, returning None if rhs == 0 or on overflow.
+    ///
+    /// Like the integer remainder, the result has the sign of self, so that
+    /// `self == (self / rhs) * rhs + self % rhs`.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert_eq!(FixedDec::<i32, 2>::new(-751).checked_rem(2), Some(FixedDec::new(-1)));
+    /// assert_eq!(FixedDec::<i32, 2>::new(751).checked_rem(0), None);
+    /// ```
+    pub fn checked_rem(self, rhs: T) -> Option<Self> {
+        self.0.checked_rem(rhs).map(Self)
+    }
+
+    /// Iterate from `start` to `end` (inclusive) by increment of `step`
+    ///
+    /// The values are computed by repeated checked addition, and the iteration stops at `end`
+    /// or before, or when the next addition would overflow. A negative step iterates
+    /// downward to `end`, and a zero step yields nothing.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// let start = FixedDec::<u32, 2>::new(0);
+    /// let end = FixedDec::new(100);
+    /// let step = FixedDec::new(25);
+    /// let values = FixedDec::range(start, end, step).map(|x| x.value()).collect::<Vec<_>>();
+    /// assert_eq!(values, [0, 25, 50, 75, 100]);
+    /// ```
+    pub fn range(start: Self, end: Self, step: Self) -> impl Iterator<Item = Self> {
+        let ascending = step.0 > T::ZERO;
+        let in_range = move |x: &Self| if ascending { *x <= end } else { *x >= end };
+        let first = Some(start).filter(|x| step.0 != T::ZERO && in_range(x));
+        core::iter::successors(first, move |x| x.checked_add(step).filter(in_range))
+    }
+
+    /// Round at a specific precision
+    ///
+    /// The digits after `prec` are truncated toward zero, keeping the precision P
+    pub fn round_at(self, prec: u32) -> Self {
+        // rounding toward zero can't overflow
+        self.round_at_with(prec, RoundingMode::TowardZero)
+    }
+
+    /// Round at a specific precision according to `mode`, keeping the precision P
+    ///
+    /// A rounding away from zero propagates to the higher digits, and can grow the integral part:
+    ///
+    /// ```
+    /// use fixeddec::{FixedDec, RoundingMode};
+    /// let f = FixedDec::<u32, 2>::new(999);
+    /// assert_eq!(f.round_at_with(1, RoundingMode::HalfUp), FixedDec::new(1000));
+    /// ```
+    ///
+    /// Panics if the rounded value doesn't fit in T, see [`Self::checked_round_at_with`]
+    /// for a non panicking version.
+    pub fn round_at_with(self, prec: u32, mode: RoundingMode) -> Self {
+        self.checked_round_at_with(prec, mode)
+            .expect("rounded value overflow")
+    }
+
+    /// Round at a specific precision according to `mode`, keeping the precision P
+    ///
+    /// If the rounded value doesn't fit in T, then None is returned
+    ///
+    /// ```
+    /// use fixeddec::{FixedDec, RoundingMode};
+    /// let f = FixedDec::<u8, 1>::new(255);
+    /// assert_eq!(f.checked_round_at_with(0, RoundingMode::TowardZero), Some(FixedDec::new(250)));
+    /// assert_eq!(f.checked_round_at_with(0, RoundingMode::HalfUp), None);
+    /// ```
+    pub fn checked_round_at_with(self, prec: u32, mode: RoundingMode) -> Option<Self> {
+        if prec >= P {
+            Some(self)
+        } else {
+            let wrap = ten_power::<T>(P - prec)?;
+            rounding::div_round(self.0, wrap, mode)?
+                .checked_mul(wrap)
+                .map(Self)
+        }
+    }
+
+    /// Round to a multiple of `step` according to `mode`, keeping the precision P
+    ///
+    /// This is used for example for the cash rounding of prices to `0.05` increments.
+    /// The sign of `step` doesn't matter, the direction of the rounding is only given by `mode`.
+    ///
+    /// ```
+    /// use fixeddec::{FixedDec, RoundingMode};
+    /// let price = FixedDec::<u32, 2>::new(123);
+    /// let nickel = FixedDec::new(5);
+    /// assert_eq!(price.round_to_multiple(nickel, RoundingMode::HalfUp), Some(FixedDec::new(125)));
+    /// assert_eq!(price.round_to_multiple(nickel, RoundingMode::Floor), Some(FixedDec::new(120)));
+    /// ```
+    ///
+    /// If step is zero, or the rounded value doesn't fit in T, then None is returned
+    pub fn round_to_multiple(self, step: Self, mode: RoundingMode) -> Option<Self> {
+        let step = if step.0 < T::ZERO {
+            T::ZERO.checked_sub(step.0)?
+        } else {
+            step.0
+        };
+        rounding::div_round(self.0, step, mode)?
+            .checked_mul(step)
+            .map(Self)
+    }
+
+    /// Return the integral part of this decimal
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// let f = FixedDec::<u32, 3>::new(1_234);
+    /// assert_eq!(f.integral(), 1);
+    /// ```
+    ///
+    /// Panics if the precision P is too big for T
+    pub fn integral(self) -> T {
+        ten_power::<T>(P)
+            .and_then(|prec| self.0.checked_div(prec))
+            .unwrap()
+    }
+
+    /// Return the value with the fractional part zeroed, truncating toward zero
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert_eq!(FixedDec::<i32, 2>::new(1234).trunc(), FixedDec::new(1200));
+    /// assert_eq!(FixedDec::<i32, 2>::new(-1234).trunc(), FixedDec::new(-1200));
+    /// ```
+    ///
+    /// Panics if the precision P is too big for T
+    pub fn trunc(self) -> Self {
+        // |integral * 10^P| <= |self| so this cannot overflow
+        Self(self.integral() * Self::scale_factor())
+    }
+
+    /// Return the fractional part of the value, with the same sign as the value
+    ///
+    /// Contrary to [`Self::fractional`] which returns the raw magnitude, the result stays
+    /// a decimal, so that `x.trunc() + x.fract() == x`.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert_eq!(FixedDec::<i32, 2>::new(1234).fract(), FixedDec::new(34));
+    /// assert_eq!(FixedDec::<i32, 2>::new(-1234).fract(), FixedDec::new(-34));
+    /// ```
+    ///
+    /// Panics if the precision P is too big for T
+    pub fn fract(self) -> Self {
+        // both have the same sign and |trunc| <= |self| so this cannot overflow
+        Self(self.0 - self.trunc().0)
+    }
+
+    /// Return the integral value after rounding the fractional part according to `mode`
+    ///
+    /// Contrary to [`Self::integral`] which always truncates, the rounding can carry
+    /// to the next integral value.
+    ///
+    /// ```
+    /// use fixeddec::{FixedDec, RoundingMode};
+    /// let f = FixedDec::<u32, 1>::new(127);
+    /// assert_eq!(f.integral(), 12);
+    /// assert_eq!(f.to_integer_rounded(RoundingMode::HalfUp), Some(13));
+    /// ```
+    ///
+    /// If the rounded value doesn't fit in T, or if the precision P is too big for T,
+    /// returns None
+    pub fn to_integer_rounded(self, mode: RoundingMode) -> Option<T> {
+        rounding::div_round(self.0, ten_power(P)?, mode)
+    }
+
+    /// Return the nearest integral value according to `mode`, the rounding complement
+    /// of the truncating [`Self::integral`]
+    ///
+    /// Contrary to [`Self::to_integer_rounded`], the result is not optional: for P = 0 the
+    /// value is already integral, and for P > 0 the magnitude of the integral part is
+    /// at most `MAX / 10`, so the carry of the rounding always fits in T.
+    ///
+    /// ```
+    /// use fixeddec::{FixedDec, RoundingMode};
+    /// let f = FixedDec::<i32, 1>::new(-25);
+    /// assert_eq!(f.round_to_integral(RoundingMode::HalfEven), -2);
+    /// assert_eq!(f.round_to_integral(RoundingMode::HalfUp), -3);
+    /// ```
+    ///
+    /// Panics if the precision P is too big for T
+    pub fn round_to_integral(self, mode: RoundingMode) -> T {
+        // with a valid precision, the rounding cannot overflow
+        self.to_integer_rounded(mode)
+            .expect("precision too big for the backing type")
+    }
+
+    /// Return the fractional part of this decimal
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// let f = FixedDec::<u32, 3>::new(1_234);
+    /// assert_eq!(f.fractional(), 234);
+    /// ```
+    ///
+    /// Panics if the precision P is too big for T
+    pub fn fractional(self) -> T {
+        self.checked_parts().unwrap().1
+    }
+
+    /// Return the integral part and the magnitude of the fractional part,
+    /// or None if the precision P is too big for T
+    pub(crate) fn checked_parts(self) -> Option<(T, T)> {
+        let prec = ten_power::<T>(P)?;
+        let fractional = self.0.checked_rem(prec)?;
+        // |fractional| < 10^P so the negation cannot overflow
+        let fractional = if fractional < T::ZERO {
+            T::ZERO - fractional
+        } else {
+            fractional
+        };
+        Some((self.0.checked_div(prec)?, fractional))
+    }
+
+    /// Return a reference to the raw backing value
+    ///
+    /// Note that this is the scaled integer (e.g. `1234` for `1.234` at P=3),
+    /// not the integral part of the value.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// let f = FixedDec::<u32, 3>::new(1_234);
+    /// assert_eq!(*f.as_raw(), 1_234);
+    /// ```
+    pub const fn as_raw(&self) -> &T {
+        &self.0
+    }
+
+    /// Return the number of decimal digits of the integral part, without the sign
+    ///
+    /// A value with a zero integral part (e.g. `0.5`) has 1 integral digit, the leading zero,
+    /// matching how the value is displayed.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert_eq!(FixedDec::<u32, 2>::new(123_456).integral_digits(), 4);
+    /// assert_eq!(FixedDec::<u32, 2>::new(50).integral_digits(), 1);
+    /// ```
+    pub fn integral_digits(self) -> u32 {
+        let ten = ten_power::<T>(1).unwrap(); // safe all types have 10
+        let mut i = self.integral() / ten;
+        let mut digits = 1;
+        while i != T::ZERO {
+            i /= ten;
+            digits += 1;
+        }
+        digits
+    }
+
+    /// Return whether the value has no fractional part
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert!(FixedDec::<u32, 2>::new(1200).is_integer());
+    /// assert!(!FixedDec::<u32, 2>::new(1201).is_integer());
+    /// ```
+    pub fn is_integer(self) -> bool {
+        self.fractional() == T::ZERO
+    }
+
+    /// Return the number of zero fractional digits from the right, between 0 and P
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert_eq!(FixedDec::<u32, 3>::new(1500).trailing_zeros_fractional(), 2);
+    /// assert_eq!(FixedDec::<u32, 3>::new(2000).trailing_zeros_fractional(), 3);
+    /// ```
+    pub fn trailing_zeros_fractional(self) -> u32 {
+        let ten = ten_power::<T>(1).unwrap(); // safe all types have 10
+        let mut v = self.0;
+        let mut zeros = 0;
+        while zeros < P && v.checked_rem(ten) == Some(T::ZERO) {
+            v /= ten;
+            zeros += 1;
+        }
+        zeros
+    }
+
+    /// Return the minimal precision representing the value without losing any digits
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert_eq!(FixedDec::<u32, 3>::new(1500).normalized_precision(), 1);
+    /// assert_eq!(FixedDec::<u32, 3>::new(2000).normalized_precision(), 0);
+    /// ```
+    pub fn normalized_precision(self) -> u32 {
+        P - self.trailing_zeros_fractional()
+    }
+
+    /// Return true if the value is zero
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert!(FixedDec::<u32, 2>::new(0).is_zero());
+    /// assert!(!FixedDec::<u32, 2>::new(1).is_zero());
+    /// ```
+    pub fn is_zero(self) -> bool {
+        self.0 == T::ZERO
+    }
+
+    /// Return true if the value is one
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert!(FixedDec::<u32, 2>::new(100).is_one());
+    /// assert!(!FixedDec::<u32, 2>::new(1).is_one());
+    /// ```
+    pub fn is_one(self) -> bool {
+        ten_power::<T>(P) == Some(self.0)
+    }
+
+    /// Return the sign of the value, as its ordering compared to zero
+    ///
+    /// Unsigned values are never `Less`.
+    ///
+    /// ```
+    /// use core::cmp::Ordering;
+    /// use fixeddec::FixedDec;
+    /// match FixedDec::<i32, 2>::new(-150).sign() {
+    ///     Ordering::Less => {}
+    ///     Ordering::Equal | Ordering::Greater => unreachable!(),
+    /// }
+    /// ```
+    pub fn sign(self) -> core::cmp::Ordering {
+        self.0.cmp(&T::ZERO)
+    }
+
+    /// Return the content value at the precision required
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// let f = FixedDec::<u32, 3>::new(1_234);
+    /// assert_eq!(f.value(), 1_234);
+    /// ```
+    pub const fn value(self) -> T {
+        self.0
+    }
+
+    /// Return a key to sort values of the same precision
+    ///
+    /// The key is the raw value, so sorting by this key gives the same order as `Ord`.
+    /// To sort values of different precisions together, use [`Self::normalize_for_sort`].
+    pub const fn sort_key(self) -> T {
+        self.0
+    }
+
+    /// Return a key to sort values of different precisions together, by scaling
+    /// the raw value to a common precision Q in the wider backing type `T::Wide`
+    ///
+    /// Keys at the same precision Q compare like the values they represent.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// let a = FixedDec::<u32, 1>::new(15);
+    /// let b = FixedDec::<u32, 3>::new(1499);
+    /// assert!(a.normalize_for_sort::<3>() > b.normalize_for_sort::<3>());
+    /// ```
+    ///
+    /// Returns None if Q is smaller than P, as the dropped digits would change the order,
+    /// or if the scaled value doesn't fit in `T::Wide`
+    pub fn normalize_for_sort<const Q: u32>(self) -> Option<T::Wide> {
+        if Q < P {
+            return None;
+        }
+        self.0.to_wide().checked_mul(ten_power(Q - P)?)
+    }
+
+    /// Parse a string containing a fractional number (e.g. "1.234")
+    ///
+    /// If the string doesn't contain any dot, then it interpreted as an integral number.
+    /// Negative numbers are accepted for signed types, and the fractional digits beyond
+    /// the precision P are truncated.
+    ///
+    /// To know why the parsing failed, use the [`core::str::FromStr`] or `TryFrom<&str>`
+    /// instances, which returns a [`ParseFixedDecError`].
+    ///
+    /// ```
+    /// use fixeddec::{FixedDec, ParseFixedDecError};
+    /// assert_eq!(FixedDec::<i32, 2>::from_str("-1.5"), Some(FixedDec::new(-150)));
+    /// assert_eq!("1.5".parse::<FixedDec<u32, 2>>(), Ok(FixedDec::new(150)));
+    /// assert_eq!(
+    ///     FixedDec::<u32, 2>::try_from("1.x"),
+    ///     Err(ParseFixedDecError::InvalidDigit { position: 2 })
+    /// );
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self> {
+        parse::parse(s).ok()
+    }
+
+    /// Parse a string containing a fractional number, and check the value is between `min` and `max`
+    ///
+    /// This is [`Self::from_str`] followed by a validation of the value, for example to
+    /// enforce business limits narrower than the range of the type. Both bounds are inclusive.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// let min = FixedDec::<u64, 2>::new(0);
+    /// let max = FixedDec::<u64, 2>::from_str("1000000.00").unwrap();
+    /// assert_eq!(FixedDec::from_str_in_range("12.50", min, max), Some(FixedDec::new(1250)));
+    /// assert_eq!(FixedDec::from_str_in_range("1000000.01", min, max), None);
+    /// ```
+    pub fn from_str_in_range(s: &str, min: Self, max: Self) -> Option<Self> {
+        Self::from_str(s).filter(|v| (min..=max).contains(v))
+    }
+
+    /// Parse the canonical string representation of a value, as written by [`Self::to_canonical_string`]
+    ///
+    /// Contrary to [`Self::from_str`], the string need to have exactly P fractional digits,
+    /// and at least one integral digit without leading zeros. For P = 0, the string shouldn't
+    /// have any dot. Zero is only accepted without sign, so each value has a single canonical string.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert_eq!(FixedDec::<i32, 2>::from_canonical_str("-1.50"), Some(FixedDec::new(-150)));
+    /// assert_eq!(FixedDec::<i32, 2>::from_canonical_str("-1.5"), None);
+    /// assert_eq!(FixedDec::<i32, 2>::from_canonical_str("01.50"), None);
+    /// assert_eq!(FixedDec::<i32, 2>::from_canonical_str("-0.00"), None);
+    /// ```
+    pub fn from_canonical_str(s: &str) -> Option<Self> {
+        let digits = s.strip_prefix('-').unwrap_or(s);
+        let negative = digits.len() != s.len();
+        let (integral, fractional) = match digits.split_once('.') {
+            Some((i, f)) => (i, Some(f)),
+            None => (digits, None),
+        };
+        let canonical = !integral.is_empty()
+            && (integral == "0" || !integral.starts_with('0'))
+            && match fractional {
+                Some(f) => P > 0 && f.len() == P as usize,
+                None => P == 0,
+            };
+        if !canonical {
+            return None;
+        }
+        Self::from_str(s).filter(|v| !(negative && v.is_zero()))
+    }
+
+    /// Parse a string containing a fractional number using `sep` as the decimal separator
+    ///
+    /// This allows parsing localized numbers such as "12,34". Any other separator, including
+    /// the dot, is rejected. The separator can't be a digit or `-`, in which case None is returned.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert_eq!(FixedDec::<u32, 2>::from_str_with_separator("12,34", ','), Some(FixedDec::new(1234)));
+    /// assert_eq!(FixedDec::<u32, 2>::from_str_with_separator("12.34", ','), None);
+    /// ```
+    pub fn from_str_with_separator(s: &str, sep: char) -> Option<Self> {
+        parse::parse_with_separator(s, sep).ok()
+    }
+
+    /// Parse a string containing a fractional number in the accounting format, where the
+    /// negative values are written between parentheses (e.g. "(12.34)" is -12.34)
+    ///
+    /// A value without parentheses is parsed like [`Self::from_str`]. The negative values
+    /// are only accepted for signed types, and a sign inside the parentheses is rejected.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert_eq!(FixedDec::<i32, 2>::from_accounting_str("(12.34)"), Some(FixedDec::new(-1234)));
+    /// assert_eq!(FixedDec::<i32, 2>::from_accounting_str("12.34"), Some(FixedDec::new(1234)));
+    /// assert_eq!(FixedDec::<u32, 2>::from_accounting_str("(12.34)"), None);
+    /// ```
+    pub fn from_accounting_str(s: &str) -> Option<Self> {
+        match s.strip_prefix('(').and_then(|s| s.strip_suffix(')')) {
+            // parsing the negative value directly allows to parse MIN
+            Some(inner) if !inner.starts_with('-') => Self::from_str(&alloc::format!("-{inner}")),
+            Some(_) => None,
+            None => Self::from_str(s),
+        }
+    }
+
+    /// Parse a string containing a fractional number with an optional trailing sign
+    /// (e.g. "12.34-" is -12.34), as used by some legacy fixed formats
+    ///
+    /// A value without trailing sign is parsed like [`Self::from_str`], and a value with
+    /// both a leading and a trailing sign is rejected.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert_eq!(FixedDec::<i32, 2>::from_str_trailing_sign("12.34-"), Some(FixedDec::new(-1234)));
+    /// assert_eq!(FixedDec::<i32, 2>::from_str_trailing_sign("12.34+"), Some(FixedDec::new(1234)));
+    /// assert_eq!(FixedDec::<i32, 2>::from_str_trailing_sign("-12.34-"), None);
+    /// ```
+    pub fn from_str_trailing_sign(s: &str) -> Option<Self> {
+        if s.starts_with(['-', '+']) && s.ends_with(['-', '+']) {
+            return None;
+        }
+        match s.strip_suffix('-') {
+            // parsing the negative value directly allows to parse MIN
+            Some(digits) => Self::from_str(&alloc::format!("-{digits}")),
+            None => Self::from_str(s.strip_suffix('+').unwrap_or(s)),
+        }
+    }
+
+    /// Parse a string containing a fractional number written by a human (e.g. " 1_000.50 ")
+    ///
+    /// Similar to [`Self::from_str`], but surrounding whitespaces are ignored, and digits
+    /// can be separated by `_` as in rust literals. A `_` need to be between two digits,
+    /// so it's rejected at the edges of the number, next to the dot, or repeated.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert_eq!(FixedDec::<u32, 2>::from_str_relaxed(" 1_000.25 "), Some(FixedDec::new(100025)));
+    /// assert_eq!(FixedDec::<u32, 2>::from_str_relaxed("_5"), None);
+    /// ```
+    pub fn from_str_relaxed(s: &str) -> Option<Self> {
+        let s = s.trim();
+        let bytes = s.as_bytes();
+        let is_digit_at = |i: usize| bytes.get(i).is_some_and(u8::is_ascii_digit);
+        for (i, b) in bytes.iter().enumerate() {
+            if *b == b'_' && (i == 0 || !is_digit_at(i - 1) || !is_digit_at(i + 1)) {
+                return None;
+            }
+        }
+        let cleaned = s.replace('_', "");
+        Self::from_str(&cleaned)
+    }
+
+    /// Parse a string containing a fractional number, rejecting any digits beyond the precision
+    ///
+    /// Contrary to [`Self::from_str`] which silently truncates the extra fractional digits,
+    /// this returns None if the string has more fractional digits than P.
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert_eq!(FixedDec::<u32, 2>::from_str_exact("1.23"), Some(FixedDec::new(123)));
+    /// assert_eq!(FixedDec::<u32, 2>::from_str_exact("1.234"), None);
+    /// ```
+    pub fn from_str_exact(s: &str) -> Option<Self> {
+        match s.split_once('.') {
+            Some((_, f1)) if f1.len() > P as usize => None,
+            _ => Self::from_str(s),
+        }
+    }
+}
+
+impl<T: SignedNumber, const P: u32> FixedDec<T, P> {
+    /// Negate the value with checked result
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert_eq!(FixedDec::<i8, 1>::new(25).checked_neg(), Some(FixedDec::new(-25)));
+    /// assert_eq!(FixedDec::<i8, 1>::MIN.checked_neg(), None);
+    /// ```
+    ///
+    /// The only value whose negation doesn't fit in T is MIN, for which None is returned
+    pub fn checked_neg(self) -> Option<Self> {
+        self.0.checked_neg().map(Self)
+    }
+
+    /// Negate the value, wrapping around at the bounds of the type
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert_eq!(FixedDec::<i8, 1>::new(25).wrapping_neg(), FixedDec::new(-25));
+    /// assert_eq!(FixedDec::<i8, 1>::MIN.wrapping_neg(), FixedDec::MIN);
+    /// ```
+    pub fn wrapping_neg(self) -> Self {
+        Self(self.0.wrapping_neg())
+    }
+
+    /// Negate the value, clamping the negation of MIN to MAX
+    ///
+    /// ```
+    /// use fixeddec::FixedDec;
+    /// assert_eq!(FixedDec::<i8, 1>::new(25).saturating_neg(), FixedDec::new(-25));
+    /// assert_eq!(FixedDec::<i8, 1>::MIN.saturating_neg(), FixedDec::MAX);
+    /// ```
+    pub fn saturating_neg(self) -> Self {
+        self.checked_neg().unwrap_or(Self::MAX)
+    }
+}
+
+// const fn can't use the generic Number operations, so the const accessors
+// are implemented for each builtin type.
+macro_rules! const_impl {
+    ($ty:ty, |$r:ident| $abs:expr) => {
+        impl<const P: u32> FixedDec<$ty, P> {
+            /// Return the integral part of this decimal, usable in const context
+            pub const fn integral_const(self) -> $ty {
+                self.0 / <$ty as Number>::TEN_POWER[P as usize]
+            }
+
+            /// Return the fractional part of this decimal, usable in const context
+            pub const fn fractional_const(self) -> $ty {
+                let $r = self.0 % <$ty as Number>::TEN_POWER[P as usize];
+                $abs
+            }
+
+            /// Parse a decimal literal (e.g. "-1.234"), usable in const context
+            ///
+            /// Contrary to [`Self::from_str`], any fractional digits beyond P is an error.
+            ///
+            /// Panics on malformed input or overflow, which is a compilation error in const context
+            pub const fn from_str_const(s: &str) -> Self {
+                let bytes = s.as_bytes();
+                let negative = !bytes.is_empty() && bytes[0] == b'-';
+                let mut i = if negative { 1 } else { 0 };
+                let mut acc: $ty = 0;
+                let mut has_digits = false;
+                let mut frac_digits: Option<u32> = None;
+                while i < bytes.len() {
+                    let c = bytes[i];
+                    if c == b'.' {
+                        assert!(frac_digits.is_none(), "multiple dots in decimal literal");
+                        frac_digits = Some(0);
+                    } else {
+                        assert!(c.is_ascii_digit(), "invalid digit in decimal literal");
+                        let d = (c - b'0') as $ty;
+                        let next = match acc.checked_mul(10) {
+                            Some(v) if negative => v.checked_sub(d),
+                            Some(v) => v.checked_add(d),
+                            None => None,
+                        };
+                        acc = match next {
+                            Some(v) => v,
+                            None => panic!("decimal literal out of range"),
+                        };
+                        has_digits = true;
+                        if let Some(f) = frac_digits {
+                            frac_digits = Some(f + 1);
+                        }
+                    }
+                    i += 1;
+                }
+                assert!(has_digits, "no digits in decimal literal");
+                let frac_digits = match frac_digits {
+                    Some(f) => f,
+                    None => 0,
+                };
+                assert!(
+                    frac_digits <= P,
+                    "too many fractional digits in decimal literal"
+                );
+                let scale = <$ty as Number>::TEN_POWER[(P - frac_digits) as usize];
+                match acc.checked_mul(scale) {
+                    Some(v) => Self::new(v),
+                    None => panic!("decimal literal out of range"),
+                }
+            }
+
+            /// Return the maximum number of characters of the `Display` output of a value
+            /// of this type without formatting flags, including the sign and the dot
+            ///
+            /// This allows to size a column or a buffer for [`Self::format_into`] at
+            /// compile time.
+            pub const fn max_display_len() -> usize {
+                // the number of digits of MAX and MIN is the number of powers of ten of the type
+                let digits = <$ty as Number>::TEN_POWER.len();
+                let p = P as usize;
+                let sign = (<$ty>::MIN != 0) as usize;
+                // there's always at least one integral digit and one fractional digit
+                let integral = if digits > p { digits - p } else { 1 };
+                let fractional = if p > 0 { p } else { 1 };
+                sign + integral + 1 + fractional
+            }
+        }
+    };
+}
+
+const_impl!(u8, |r| r);
+const_impl!(u16, |r| r);
+const_impl!(u32, |r| r);
+const_impl!(u64, |r| r);
+const_impl!(u128, |r| r);
+const_impl!(i8, |r| r.abs());
+const_impl!(i16, |r| r.abs());
+const_impl!(i32, |r| r.abs());
+const_impl!(i64, |r| r.abs());
+const_impl!(i128, |r| r.abs());
+
+// extracting the raw value can't be done generically for any T because of the orphan rules
+macro_rules! raw_from_impl {
+    ($($ty:ty),*) => {
+        $(
+            /// Extract the raw backing value, which is the scaled integer, not the integral part
+            impl<const P: u32> From<FixedDec<$ty, P>> for $ty {
+                fn from(value: FixedDec<$ty, P>) -> $ty {
+                    value.0
+                }
+            }
+        )*
+    };
+}
+
+raw_from_impl!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl<T: Number, const P: u32> Add for FixedDec<T, P> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl<T: Number, const P: u32> AddAssign for FixedDec<T, P> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl<T: Number, const P: u32> Sub for FixedDec<T, P> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl<T: Number, const P: u32> SubAssign for FixedDec<T, P> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0
+    }
+}
+
+impl<T: Number, const P: u32> Mul<T> for FixedDec<T, P> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self(self.0 * rhs)
+    }
+}
+
+impl<T: Number, const P: u32> MulAssign<T> for FixedDec<T, P> {
+    fn mul_assign(&mut self, rhs: T) {
+        self.0 *= rhs
+    }
+}
+
+/// Multiplication by a FixedDec of precision 0, which is a scalar
+///
+/// ```
+/// use fixeddec::FixedDec;
+/// let price = FixedDec::<u32, 2>::new(250);
+/// let quantity = FixedDec::<u32, 0>::new(3);
+/// assert_eq!(price * quantity, FixedDec::new(750));
+/// ```
+impl<T: Number, const P: u32> Mul<FixedDec<T, 0>> for FixedDec<T, P> {
+    type Output = Self;
+
+    fn mul(self, rhs: FixedDec<T, 0>) -> Self::Output {
+        Self(self.0 * rhs.0)
+    }
+}
+
+impl<T: Number, const P: u32> MulAssign<FixedDec<T, 0>> for FixedDec<T, P> {
+    fn mul_assign(&mut self, rhs: FixedDec<T, 0>) {
+        self.0 *= rhs.0
+    }
+}
+
+impl<T: Number, const P: u32> Div<T> for FixedDec<T, P> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Self(self.0 / rhs)
+    }
+}
+
+impl<T: Number, const P: u32> DivAssign<T> for FixedDec<T, P> {
+    fn div_assign(&mut self, rhs: T) {
+        self.0 /= rhs
+    }
+}
+
+/// Negation, defined for the signed backing types
+///
+/// Like the builtin integers, negating MIN panics in debug builds and wraps in release
+/// builds, see [`FixedDec::checked_neg`] for a checked version
+///
+/// ```
+/// use fixeddec::FixedDec;
+/// assert_eq!(-FixedDec::<i32, 2>::new(150), FixedDec::new(-150));
+/// ```
+impl<T: SignedNumber, const P: u32> Neg for FixedDec<T, P> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+/// Remainder of the raw value by a scalar, with the sign of self like the integer remainder
+///
+/// Panics if rhs is zero, or on overflow (`MIN % -1`), see [`FixedDec::checked_rem`]
+/// for a checked version
+///
+/// ```
+/// use fixeddec::FixedDec;
+/// assert_eq!(FixedDec::<i32, 2>::new(751) % 2, FixedDec::new(1));
+/// assert_eq!(FixedDec::<i32, 2>::new(-751) % 2, FixedDec::new(-1));
+/// ```
+impl<T: Number, const P: u32> Rem<T> for FixedDec<T, P> {
+    type Output = Self;
+
+    fn rem(self, rhs: T) -> Self::Output {
+        self.checked_rem(rhs)
+            .expect("attempt to calculate the remainder with a divisor of zero or overflow")
+    }
+}
+
+impl<T: Number, const P: u32> RemAssign<T> for FixedDec<T, P> {
+    fn rem_assign(&mut self, rhs: T) {
+        *self = *self % rhs;
+    }
+}
+
+/// Product of FixedDec values, see [`FixedDec::try_product`] for a checked version
+///
+/// Panics if any intermediate result overflow the type T
+impl<T: Number, const P: u32> Product for FixedDec<T, P> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self::try_product(iter).expect("product overflow")
+    }
+}
+
+impl<'a, T: Number, const P: u32> Product<&'a FixedDec<T, P>> for FixedDec<T, P> {
+    fn product<I: Iterator<Item = &'a FixedDec<T, P>>>(iter: I) -> Self {
+        Self::try_product(iter.copied()).expect("product overflow")
+    }
+}
+
+// implement the operators for the borrowed variants of the operands, by forwarding to the owned implementation
+macro_rules! forward_ref_binop {
+    ($imp:ident, $method:ident, $rhs:ty) => {
+        impl<T: Number, const P: u32> $imp<$rhs> for &FixedDec<T, P> {
+            type Output = FixedDec<T, P>;
+
+            fn $method(self, rhs: $rhs) -> Self::Output {
+                $imp::$method(*self, rhs)
+            }
+        }
+
+        impl<T: Number, const P: u32> $imp<&$rhs> for FixedDec<T, P> {
+            type Output = FixedDec<T, P>;
+
+            fn $method(self, rhs: &$rhs) -> Self::Output {
+                $imp::$method(self, *rhs)
+            }
+        }
+
+        impl<T: Number, const P: u32> $imp<&$rhs> for &FixedDec<T, P> {
+            type Output = FixedDec<T, P>;
+
+            fn $method(self, rhs: &$rhs) -> Self::Output {
+                $imp::$method(*self, *rhs)
+            }
+        }
+    };
 }
 
-impl<T: Number, const P: u32> Div<T> for FixedDec<T, P> {
-    type Output = Self;
+forward_ref_binop!(Add, add, FixedDec<T, P>);
+forward_ref_binop!(Sub, sub, FixedDec<T, P>);
+forward_ref_binop!(Mul, mul, T);
+forward_ref_binop!(Mul, mul, FixedDec<T, 0>);
+forward_ref_binop!(Div, div, T);
+forward_ref_binop!(Rem, rem, T);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integral_fractional() {
+        let x1: FixedDec<u32, 3> = FixedDec::new(1000);
+        let x2: FixedDec<u32, 4> = FixedDec::new(1000);
+        let x3: FixedDec<u32, 3> = FixedDec::new(1234);
+        let i1: FixedDec<i32, 3> = FixedDec::new(-1234);
+
+        assert_eq!(x1.integral(), 1);
+        assert_eq!(x1.fractional(), 0);
+
+        assert_eq!(x2.integral(), 0);
+        assert_eq!(x2.fractional(), 1000);
+
+        assert_eq!(x3.integral(), 1);
+        assert_eq!(x3.fractional(), 234);
+
+        assert_eq!(i1.integral(), -1);
+        assert_eq!(i1.fractional(), 234);
+    }
+
+    #[test]
+    fn formatting() {
+        let x1: FixedDec<u32, 3> = FixedDec::new(1000);
+        let x2: FixedDec<u32, 4> = FixedDec::new(1000);
+        let x3: FixedDec<u32, 3> = FixedDec::new(1234);
+        let x4: FixedDec<u32, 2> = FixedDec::new(123456);
+
+        assert_eq!(alloc::format!("{}", x1), "1.000");
+        assert_eq!(alloc::format!("{}", x2), "0.1000");
+        assert_eq!(alloc::format!("{}", x3), "1.234");
+        assert_eq!(alloc::format!("{}", x4), "1234.56");
+    }
+
+    #[test]
+    fn rounding() {
+        let x1: FixedDec<u32, 3> = FixedDec::new(1000);
+        let x2: FixedDec<u32, 4> = FixedDec::new(1000);
+        let x3: FixedDec<u32, 3> = FixedDec::new(1234);
+        let x4: FixedDec<u32, 2> = FixedDec::new(123456);
+
+        assert_eq!(x1.round_at(2), x1);
+        assert_eq!(x2.round_at(2), x2);
+        assert_eq!(x3.round_at(2), FixedDec::new(1230));
+        assert_eq!(x4.round_at(1), FixedDec::new(123450));
+        assert_eq!(x4.round_at(3), FixedDec::new(123456));
+    }
+
+    #[test]
+    fn rounding_with() {
+        use RoundingMode::*;
+
+        let x1 = FixedDec::<u32, 2>::new(999);
+        let x2 = FixedDec::<u8, 1>::new(255);
+        let i1 = FixedDec::<i32, 3>::new(-1234);
+        let i2 = FixedDec::<i32, 3>::new(-1250);
+
+        assert_eq!(x1.round_at_with(1, HalfUp), FixedDec::new(1000));
+        assert_eq!(x1.round_at_with(0, HalfUp), FixedDec::new(1000));
+        assert_eq!(x1.round_at_with(1, TowardZero), FixedDec::new(990));
+        assert_eq!(x1.round_at_with(2, HalfUp), x1);
+        assert_eq!(
+            FixedDec::<u32, 3>::new(9999).round_at_with(0, HalfEven),
+            FixedDec::new(10000)
+        );
+
+        assert_eq!(x2.checked_round_at_with(0, HalfUp), None);
+        assert_eq!(
+            x2.checked_round_at_with(0, HalfDown),
+            Some(FixedDec::new(250))
+        );
+        assert_eq!(x2.checked_round_at_with(0, HalfEven), None);
+        assert_eq!(x2.checked_round_at_with(0, Floor), Some(FixedDec::new(250)));
+
+        assert_eq!(i1.round_at(2), FixedDec::new(-1230));
+        assert_eq!(FixedDec::<i8, 1>::MIN.round_at(0), FixedDec::new(-120));
+        assert_eq!(i1.round_at_with(2, Floor), FixedDec::new(-1240));
+        assert_eq!(i1.round_at_with(2, Ceiling), FixedDec::new(-1230));
+        assert_eq!(i2.round_at_with(1, HalfUp), FixedDec::new(-1300));
+        assert_eq!(i2.round_at_with(1, HalfEven), FixedDec::new(-1200));
+        assert_eq!(i2.round_at_with(1, HalfDown), FixedDec::new(-1200));
+        assert_eq!(
+            FixedDec::<i8, 1>::MIN.checked_round_at_with(0, HalfUp),
+            None
+        );
+    }
+
+    #[test]
+    fn from_str() {
+        let x0 = FixedDec::<u32, 0>::new(1234);
+        let x1 = FixedDec::<u32, 3>::new(1234);
+        let x2 = FixedDec::<u32, 3>::new(10234);
+        let x3 = FixedDec::<u32, 4>::new(10234);
+        let x4 = FixedDec::<u32, 4>::new(12340);
+
+        assert_eq!(FixedDec::from_str("1234"), Some(x0));
+        assert_eq!(FixedDec::from_str("1.234"), Some(x1));
+        assert_eq!(FixedDec::from_str("10.234"), Some(x2));
+        assert_eq!(FixedDec::from_str("1.0234"), Some(x3));
+        assert_eq!(FixedDec::from_str("1.02345"), Some(x3));
+        assert_eq!(FixedDec::from_str("1.234"), Some(x4));
+    }
+
+    #[test]
+    fn zero_one() {
+        assert_eq!(FixedDec::<u32, 3>::ZERO, FixedDec::new(0));
+        assert_eq!(FixedDec::<u32, 3>::ONE, FixedDec::new(1000));
+        assert_eq!(FixedDec::<i8, 2>::ONE, FixedDec::from_integral(1).unwrap());
+        assert_eq!(FixedDec::<u128, 38>::ONE.value(), 10u128.pow(38));
+        assert_eq!(FixedDec::<i64, 0>::ONE.value(), 1);
+
+        assert!(FixedDec::<u32, 3>::ZERO.is_zero());
+        assert!(FixedDec::<u32, 3>::ONE.is_one());
+        assert!(!FixedDec::<u32, 3>::ZERO.is_one());
+        assert!(!FixedDec::<i32, 3>::new(-1000).is_one());
+        assert!(!FixedDec::<u32, 3>::new(1).is_one());
+        assert_eq!(FixedDec::<i16, 1>::ONE + FixedDec::ZERO, FixedDec::ONE);
+    }
+
+    #[test]
+    fn round_to_multiple() {
+        type F = FixedDec<i32, 2>;
+        let nickel = F::new(5);
+        let quarter = F::new(25);
+        let cases = [
+            (123, nickel, RoundingMode::HalfUp, 125),
+            (122, nickel, RoundingMode::HalfUp, 120),
+            (-123, nickel, RoundingMode::HalfUp, -125),
+            (123, nickel, RoundingMode::Floor, 120),
+            (-123, nickel, RoundingMode::Floor, -125),
+            (121, nickel, RoundingMode::Ceiling, 125),
+            (-121, nickel, RoundingMode::Ceiling, -120),
+            (125, nickel, RoundingMode::Ceiling, 125),
+            (112, quarter, RoundingMode::HalfUp, 100),
+            (113, quarter, RoundingMode::HalfUp, 125),
+            (150, F::new(100), RoundingMode::HalfEven, 200),
+            (250, F::new(100), RoundingMode::HalfEven, 200),
+            (250, F::new(100), RoundingMode::HalfDown, 200),
+            (123, F::new(-5), RoundingMode::Floor, 120),
+            (7, F::new(3), RoundingMode::AwayFromZero, 9),
+            (-7, F::new(3), RoundingMode::TowardZero, -6),
+        ];
+        for (v, step, mode, expected) in cases {
+            assert_eq!(
+                F::new(v).round_to_multiple(step, mode),
+                Some(F::new(expected)),
+                "{v} {step} {mode:?}"
+            );
+        }
+
+        assert_eq!(
+            F::new(123).round_to_multiple(F::new(0), RoundingMode::HalfUp),
+            None
+        );
+        assert_eq!(
+            F::MAX.round_to_multiple(nickel, RoundingMode::Ceiling),
+            None
+        );
+        assert_eq!(
+            F::new(1).round_to_multiple(F::MIN, RoundingMode::HalfUp),
+            None
+        );
+        assert_eq!(
+            FixedDec::<u8, 1>::new(253).round_to_multiple(FixedDec::new(5), RoundingMode::Floor),
+            Some(FixedDec::new(250))
+        );
+    }
+
+    #[test]
+    fn scaled_one() {
+        type F = FixedDec<u32, 3>;
+        assert_eq!(F::scaled_one(0), Some(F::ONE));
+        assert_eq!(F::scaled_one(-1), Some(F::new(100)));
+        assert_eq!(F::scaled_one(-3), Some(F::new(1)));
+        assert_eq!(F::scaled_one(-4), None);
+        assert_eq!(F::scaled_one(6), Some(F::new(1_000_000_000)));
+        assert_eq!(F::scaled_one(7), None);
+        assert_eq!(F::scaled_one(i32::MAX), None);
+        assert_eq!(F::scaled_one(i32::MIN), None);
+
+        assert_eq!(FixedDec::<i8, 0>::scaled_one(2), Some(FixedDec::new(100)));
+        assert_eq!(FixedDec::<i8, 0>::scaled_one(3), None);
+        assert_eq!(FixedDec::<i8, 2>::scaled_one(-2), Some(FixedDec::new(1)));
+        assert_eq!(
+            FixedDec::<u128, 38>::scaled_one(0),
+            Some(FixedDec::new(10u128.pow(38)))
+        );
+        assert_eq!(FixedDec::<u128, 38>::scaled_one(1), None);
+
+        // the precision itself need to be valid
+        assert_eq!(FixedDec::<u8, 3>::scaled_one(-1), None);
+    }
+
+    #[test]
+    fn scale_factor() {
+        assert_eq!(FixedDec::<u32, 0>::scale_factor(), 1);
+        assert_eq!(FixedDec::<u32, 3>::scale_factor(), 1000);
+        assert_eq!(FixedDec::<i8, 2>::scale_factor(), 100);
+        assert_eq!(FixedDec::<u128, 38>::scale_factor(), 10u128.pow(38));
+
+        assert_eq!(ten_power::<u64>(19), Some(10u64.pow(19)));
+        assert_eq!(ten_power::<u64>(20), None);
+        assert_eq!(ten_power::<i64>(19), None);
+        assert_eq!(ten_power::<u8>(2), Some(100));
+        assert_eq!(ten_power::<u8>(3), None);
+    }
+
+    #[test]
+    fn raw() {
+        let x1 = FixedDec::<u32, 3>::new(1234);
+        let x2 = FixedDec::<i128, 2>::new(-5);
+
+        assert_eq!(*x1.as_raw(), 1234);
+        assert_eq!(u32::from(x1), 1234);
+        let r: i128 = x2.into();
+        assert_eq!(r, -5);
+        assert_eq!(*x2.as_raw(), x2.value());
+    }
+
+    #[test]
+    fn sub_signed() {
+        let a = FixedDec::<u32, 2>::new(200);
+        let b = FixedDec::<u32, 2>::new(500);
+        assert_eq!(a.sub_signed(b), Some(FixedDec::<i32, 2>::new(-300)));
+        assert_eq!(b.sub_signed(a), Some(FixedDec::new(300)));
+        assert_eq!(a.sub_signed(a), Some(FixedDec::new(0)));
+
+        // underflow to the signed MIN, but not below
+        let zero = FixedDec::<u32, 0>::new(0);
+        let half = FixedDec::<u32, 0>::new(1 << 31);
+        assert_eq!(zero.sub_signed(half), Some(FixedDec::<i32, 0>::MIN));
+        assert_eq!(zero.sub_signed(FixedDec::new((1 << 31) + 1)), None);
+        assert_eq!(half.sub_signed(zero), None);
+        let small = FixedDec::<u8, 1>::new(5);
+        assert_eq!(
+            small.sub_signed(FixedDec::new(133)),
+            Some(FixedDec::<i8, 1>::MIN)
+        );
+        assert_eq!(small.sub_signed(FixedDec::MAX), None);
+
+        // 10^19 fits in u64 but not in i64
+        assert_eq!(
+            FixedDec::<u64, 19>::new(0).sub_signed(FixedDec::new(1)),
+            None
+        );
+
+        let i = FixedDec::<i16, 1>::new(-5);
+        assert_eq!(i.sub_signed(FixedDec::new(20)), Some(FixedDec::new(-25)));
+        assert_eq!(FixedDec::<i16, 1>::MAX.sub_signed(i), None);
+    }
+
+    #[test]
+    fn neg() {
+        type F = FixedDec<i8, 1>;
+        assert_eq!(-F::new(25), F::new(-25));
+        assert_eq!(-F::new(-25), F::new(25));
+        assert_eq!(-F::MAX, F::new(-127));
+        assert_eq!(-F::new(0), F::new(0));
+
+        // the negation of MIN overflows
+        assert_eq!(F::MIN.checked_neg(), None);
+        assert_eq!(F::MIN.wrapping_neg(), F::MIN);
+        assert_eq!(F::MIN.saturating_neg(), F::MAX);
+        assert_eq!(F::new(-127).checked_neg(), Some(F::MAX));
+        assert_eq!(F::new(-127).wrapping_neg(), F::MAX);
+        assert_eq!(F::new(-127).saturating_neg(), F::MAX);
+        assert_eq!(F::MAX.checked_neg(), Some(F::new(-127)));
+        assert_eq!(F::MAX.saturating_neg(), F::new(-127));
+
+        assert_eq!(FixedDec::<i128, 10>::MIN.checked_neg(), None);
+        assert_eq!(
+            FixedDec::<i64, 4>::new(-12_345).checked_neg(),
+            Some(FixedDec::new(12_345))
+        );
+    }
+
+    #[test]
+    fn abs_diff() {
+        let x1 = FixedDec::<u32, 2>::new(100);
+        let x2 = FixedDec::<u32, 2>::new(250);
+        let i1 = FixedDec::<i32, 2>::new(-100);
+
+        assert_eq!(x1.abs_diff(x2), Some(FixedDec::new(150)));
+        assert_eq!(x2.abs_diff(x1), Some(FixedDec::new(150)));
+        assert_eq!(x1.abs_diff(x1), Some(FixedDec::new(0)));
+        assert_eq!(
+            FixedDec::<u32, 2>::MIN.abs_diff(FixedDec::MAX),
+            Some(FixedDec::MAX)
+        );
+
+        assert_eq!(i1.abs_diff(FixedDec::new(250)), Some(FixedDec::new(350)));
+        assert_eq!(i1.abs_diff(FixedDec::new(-250)), Some(FixedDec::new(150)));
+        assert_eq!(FixedDec::<i32, 2>::MIN.abs_diff(FixedDec::MAX), None);
+    }
+
+    #[test]
+    fn ulps_between() {
+        let a = FixedDec::<u32, 2>::from_str("1.50").unwrap();
+        let b = FixedDec::<u32, 2>::from_str("1.51").unwrap();
+        assert_eq!(a.ulps_between(b), Some(1));
+        assert_eq!(b.ulps_between(a), Some(1));
+        assert_eq!(a.ulps_between(a), Some(0));
+        assert_eq!(
+            FixedDec::<u32, 2>::MIN.ulps_between(FixedDec::MAX),
+            Some(u32::MAX)
+        );
+
+        let c = FixedDec::<i32, 3>::from_str("-0.001").unwrap();
+        let d = FixedDec::<i32, 3>::from_str("0.002").unwrap();
+        assert_eq!(c.ulps_between(d), Some(3));
+        assert_eq!(FixedDec::<i32, 3>::MIN.ulps_between(FixedDec::MAX), None);
+    }
+
+    #[test]
+    fn approx_eq() {
+        let tol = FixedDec::<i32, 2>::from_str("0.01").unwrap();
+        let a = FixedDec::<i32, 2>::from_str("1.50").unwrap();
+        assert!(a.approx_eq(a, tol));
+        assert!(a.approx_eq(FixedDec::new(151), tol));
+        assert!(a.approx_eq(FixedDec::new(149), tol));
+        assert!(!a.approx_eq(FixedDec::new(152), tol));
+        assert!(!a.approx_eq(FixedDec::new(-150), tol));
+        assert!(a.approx_eq(FixedDec::new(152), FixedDec::new(2)));
+        assert!(a.approx_eq(FixedDec::new(152), FixedDec::MAX));
+        assert!(!a.approx_eq(FixedDec::new(151), FixedDec::new(0)));
+        assert!(!FixedDec::<i32, 2>::MIN.approx_eq(FixedDec::MAX, FixedDec::MAX));
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn ref_ops() {
+        let a = FixedDec::<u32, 2>::new(150);
+        let b = FixedDec::<u32, 2>::new(25);
+
+        assert_eq!(&a + &b, FixedDec::new(175));
+        assert_eq!(a + &b, FixedDec::new(175));
+        assert_eq!(&a + b, FixedDec::new(175));
+        assert_eq!(&a - &b, FixedDec::new(125));
+        assert_eq!(a - &b, FixedDec::new(125));
+        assert_eq!(&a - b, FixedDec::new(125));
+        assert_eq!(&a * &3, FixedDec::new(450));
+        assert_eq!(a * &3, FixedDec::new(450));
+        assert_eq!(&a * 3, FixedDec::new(450));
+        assert_eq!(&a / &3, FixedDec::new(50));
+        assert_eq!(a / &3, FixedDec::new(50));
+        assert_eq!(&a / 3, FixedDec::new(50));
+        assert_eq!(&a % &4, FixedDec::new(2));
+        assert_eq!(a % &4, FixedDec::new(2));
+        assert_eq!(&a % 4, FixedDec::new(2));
+
+        let values = [a, b, a];
+        let total = values.iter().fold(FixedDec::new(0), |acc, x| acc + x);
+        assert_eq!(total, FixedDec::new(325));
+
+        let three = FixedDec::<u32, 0>::new(3);
+        assert_eq!(&a * &three, FixedDec::new(450));
+        assert_eq!(a * &three, FixedDec::new(450));
+        assert_eq!(&a * three, FixedDec::new(450));
+    }
+
+    #[test]
+    fn mul_scalar_fixed() {
+        let price = FixedDec::<u32, 2>::from_str("2.50").unwrap();
+        let quantity = FixedDec::<u32, 0>::new(3);
+        assert_eq!(price * quantity, FixedDec::from_str("7.50").unwrap());
+
+        let mut total = FixedDec::<i64, 4>::new(-12_5000);
+        total *= FixedDec::<i64, 0>::new(2);
+        assert_eq!(total, FixedDec::new(-25_0000));
+
+        let x = FixedDec::<u8, 0>::new(7);
+        assert_eq!(x * FixedDec::<u8, 0>::new(3), FixedDec::new(21));
+    }
+
+    #[test]
+    fn set_precision_saturating() {
+        let x1 = FixedDec::<u8, 0>::new(3);
+        let x2 = FixedDec::<u8, 1>::new(25);
+        let i1 = FixedDec::<i8, 0>::new(-2);
+
+        assert_eq!(x1.set_precision_saturating::<2>(), FixedDec::MAX);
+        assert_eq!(x1.set_precision_saturating::<1>(), FixedDec::new(30));
+        assert_eq!(x2.set_precision_saturating::<2>(), FixedDec::new(250));
+        assert_eq!(x2.set_precision_saturating::<0>(), FixedDec::new(2));
+        assert_eq!(i1.set_precision_saturating::<1>(), FixedDec::new(-20));
+        assert_eq!(i1.set_precision_saturating::<2>(), FixedDec::MIN);
+        assert_eq!(
+            FixedDec::<i8, 0>::new(2).set_precision_saturating::<2>(),
+            FixedDec::MAX
+        );
+    }
+
+    #[test]
+    fn to_f64() {
+        assert_eq!(FixedDec::<u32, 2>::new(125).to_f64(), 1.25);
+        assert_eq!(FixedDec::<i64, 3>::new(-500).to_f64(), -0.5);
+        assert_eq!(FixedDec::<u8, 0>::MAX.to_f64(), 255.0);
+        assert_eq!(FixedDec::<u32, 1>::new(1).to_f64(), 0.1);
+
+        // huge values are rounded to the nearest float, but still finite
+        let huge = FixedDec::<u128, 0>::MAX;
+        assert_eq!(huge.to_f64_checked(), Some(u128::MAX as f64));
+        let huge = FixedDec::<u128, 38>::MAX.to_f64_checked().unwrap();
+        assert!((huge - 3.402823669209385).abs() < 1e-15);
+        let tiny = FixedDec::<i128, 38>::new(-1);
+        assert_eq!(tiny.to_f64_checked(), Some(-1e-38));
+
+        // precision loss above 2^53
+        let big = FixedDec::<u64, 0>::new((1 << 53) + 1);
+        assert_eq!(big.to_f64_checked(), Some((1u64 << 53) as f64));
+    }
+
+    #[test]
+    fn saturating_from_f64() {
+        type F = FixedDec<i32, 2>;
+        assert_eq!(F::saturating_from_f64(f64::INFINITY), F::MAX);
+        assert_eq!(F::saturating_from_f64(f64::NEG_INFINITY), F::MIN);
+        assert_eq!(F::saturating_from_f64(f64::NAN), F::new(0));
+        assert_eq!(F::saturating_from_f64(-f64::NAN), F::new(0));
+        assert_eq!(F::saturating_from_f64(1e10), F::MAX);
+        assert_eq!(F::saturating_from_f64(-1e10), F::MIN);
+        assert_eq!(F::saturating_from_f64(-12.345), F::new(-1235));
+        assert_eq!(F::saturating_from_f64(0.0), F::new(0));
+        assert_eq!(F::saturating_from_f64(f64::MIN_POSITIVE), F::new(0));
+
+        type U = FixedDec<u64, 4>;
+        assert_eq!(U::saturating_from_f64(f64::INFINITY), U::MAX);
+        assert_eq!(U::saturating_from_f64(f64::NEG_INFINITY), U::MIN);
+        assert_eq!(U::saturating_from_f64(-1.0), U::MIN);
+        assert_eq!(U::saturating_from_f64(f64::NAN), U::new(0));
+        assert_eq!(U::saturating_from_f64(1.5), U::new(15000));
+    }
+
+    #[test]
+    fn from_f64() {
+        type F = FixedDec<i32, 2>;
+
+        assert_eq!(F::from_f64_checked(0.5), Some((F::new(50), false)));
+        assert_eq!(F::from_f64_checked(0.1), Some((F::new(10), true)));
+        assert_eq!(F::from_f64_checked(0.0), Some((F::new(0), false)));
+        assert_eq!(F::from_f64_checked(-0.0), Some((F::new(0), false)));
+        assert_eq!(F::from_f64_checked(-12.75), Some((F::new(-1275), false)));
+        assert_eq!(F::from_f64_checked(0.125), Some((F::new(13), true)));
+        assert_eq!(F::from_f64_checked(-0.125), Some((F::new(-13), true)));
+        assert_eq!(F::from_f64_checked(0.124), Some((F::new(12), true)));
+        assert_eq!(F::from_f64_checked(1e-300), Some((F::new(0), true)));
+        assert_eq!(
+            F::from_f64_checked(f64::MIN_POSITIVE / 2.0),
+            Some((F::new(0), true))
+        );
+        assert_eq!(F::from_f64_checked(1024.0), Some((F::new(102400), false)));
+        assert_eq!(F::from_f64_checked(1e300), None);
+        assert_eq!(F::from_f64_checked(f64::NAN), None);
+        assert_eq!(F::from_f64_checked(f64::INFINITY), None);
+        assert_eq!(F::from_f64_checked(f64::NEG_INFINITY), None);
+
+        assert_eq!(FixedDec::<u32, 2>::from_f64(-1.0), None);
+        assert_eq!(FixedDec::<u8, 2>::from_f64(2.55), Some(FixedDec::new(255)));
+        assert_eq!(FixedDec::<u8, 2>::from_f64(2.56), None);
+        assert_eq!(
+            FixedDec::<u8, 2>::from_f64_checked(0.5),
+            Some((FixedDec::new(50), false))
+        );
+        assert_eq!(
+            FixedDec::<i8, 0>::from_f64_checked(-128.0),
+            Some((FixedDec::MIN, false))
+        );
+        // exact computation beyond the f64 precision
+        assert_eq!(
+            FixedDec::<u128, 30>::from_f64_checked(0.5),
+            Some((FixedDec::new(5 * 10u128.pow(29)), false))
+        );
+    }
+
+    #[test]
+    fn hash_eq() {
+        use core::hash::{Hash, Hasher};
+        use rand::{Rng, SeedableRng, rngs::SmallRng};
+
+        // FNV-1a
+        struct TestHasher(u64);
+        impl Hasher for TestHasher {
+            fn finish(&self) -> u64 {
+                self.0
+            }
+            fn write(&mut self, bytes: &[u8]) {
+                for b in bytes {
+                    self.0 = (self.0 ^ *b as u64).wrapping_mul(0x100000001b3);
+                }
+            }
+        }
+        fn hash<H: Hash>(h: H) -> u64 {
+            let mut hasher = TestHasher(0xcbf29ce484222325);
+            h.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let mut rng = SmallRng::seed_from_u64(0x2545f4914f6cdd1d);
+        for _ in 0..1000 {
+            let v = rng.random_range(-999..=999);
+            let a = FixedDec::<i32, 2>::new(v);
+            let b = FixedDec::<i32, 2>::from_parts(v / 100, v % 100).unwrap();
+            let c = FixedDec::<i32, 2>::new(v / 2 * 2);
+            assert_eq!(a, b);
+            assert_eq!(hash(a), hash(b));
+            assert_eq!(hash(a), hash(v));
+            // only equality implies equal hashes, unequal values can collide
+            if a == c {
+                assert_eq!(hash(a), hash(c));
+            }
+        }
+
+        let x1 = FixedDec::<u32, 2>::from_str("1.50").unwrap();
+        let x2 = FixedDec::<u32, 2>::from_str("1.5").unwrap();
+        let x3 = FixedDec::<u32, 1>::new(15).set_precision::<2>().unwrap();
+        assert_eq!(hash(x1), hash(x2));
+        assert_eq!(hash(x1), hash(x3));
+
+        let zero = FixedDec::<i32, 2>::new(0);
+        assert_eq!(FixedDec::new(-5).round_at(0), zero);
+        assert_eq!(hash(FixedDec::<i32, 2>::new(-5).round_at(0)), hash(zero));
+    }
+
+    #[test]
+    fn try_into_rounded() {
+        use RoundingMode::*;
+
+        let x1 = FixedDec::<u64, 6>::new(123_456_789);
+        let x2 = FixedDec::<u32, 1>::new(25);
+        let i1 = FixedDec::<i64, 4>::new(-12_345);
+
+        assert_eq!(
+            x1.try_into_rounded::<u16, 2>(HalfUp),
+            Some(FixedDec::new(12_346))
+        );
+        assert_eq!(
+            x1.try_into_rounded::<u16, 2>(TowardZero),
+            Some(FixedDec::new(12_345))
+        );
+        assert_eq!(x1.try_into_rounded::<u16, 3>(HalfUp), None);
+        assert_eq!(
+            x1.try_into_rounded::<u8, 0>(HalfUp),
+            Some(FixedDec::new(123))
+        );
+        assert_eq!(x1.try_into_rounded::<u8, 1>(HalfUp), None);
+        assert_eq!(
+            x1.try_into_rounded::<u128, 8>(HalfUp),
+            Some(FixedDec::new(12_345_678_900))
+        );
+
+        assert_eq!(
+            x2.try_into_rounded::<u8, 2>(HalfUp),
+            Some(FixedDec::new(250))
+        );
+        assert_eq!(x2.try_into_rounded::<u8, 3>(HalfUp), None);
+
+        assert_eq!(
+            i1.try_into_rounded::<i8, 1>(HalfEven),
+            Some(FixedDec::new(-12))
+        );
+        assert_eq!(
+            i1.try_into_rounded::<i8, 1>(Floor),
+            Some(FixedDec::new(-13))
+        );
+        assert_eq!(i1.try_into_rounded::<u8, 1>(HalfUp), None);
+    }
+
+    #[test]
+    fn rem() {
+        let x1 = FixedDec::<i32, 2>::new(1000);
+        let i1 = FixedDec::<i32, 2>::new(-1000);
+
+        assert_eq!(x1 % 3, FixedDec::new(1));
+        assert_eq!(x1 % -3, FixedDec::new(1));
+        assert_eq!(i1 % 3, FixedDec::new(-1));
+        assert_eq!(i1 % -3, FixedDec::new(-1));
+        assert_eq!(x1 % 4, FixedDec::new(0));
+        assert_eq!(i1.checked_rem(3), Some(FixedDec::new(-1)));
+        assert_eq!(i1.checked_rem(0), None);
+        assert_eq!(FixedDec::<i32, 2>::MIN.checked_rem(-1), None);
+
+        // consistent with the division
+        for (v, d) in [
+            (1000, 3),
+            (-1000, 3),
+            (-1000, -3),
+            (7, 10),
+            (-7, 10),
+            (0, 5),
+        ] {
+            let f = FixedDec::<i32, 2>::new(v);
+            assert_eq!((f / d) * d + f % d, f);
+            assert_eq!(f.div_rem(d).map(|(_, r)| r), Some(f % d));
+        }
+
+        let mut f = FixedDec::<i32, 2>::new(-751);
+        f %= 2;
+        assert_eq!(f, FixedDec::new(-1));
+        let mut f = FixedDec::<u32, 2>::new(751);
+        f %= 10;
+        assert_eq!(f, FixedDec::new(1));
+
+        // the fractional part is still the magnitude
+        assert_eq!(FixedDec::<i32, 2>::new(-1234).fractional(), 34);
+        assert_eq!(FixedDec::<i8, 2>::MIN.fractional(), 28);
+    }
+
+    #[test]
+    #[should_panic(expected = "divisor of zero")]
+    fn rem_by_zero() {
+        let _ = FixedDec::<i32, 2>::new(100) % 0;
+    }
+
+    #[test]
+    fn max_display_len() {
+        use alloc::string::ToString;
+
+        const LEN: usize = FixedDec::<u32, 2>::max_display_len();
+        assert_eq!(LEN, 11);
+        assert_eq!(FixedDec::<u32, 2>::MAX.to_string().len(), LEN);
+        assert_eq!(FixedDec::<i64, 4>::max_display_len(), 21);
+        assert_eq!(FixedDec::<i64, 4>::MIN.to_string().len(), 21);
+
+        fn check<T: Number, const P: u32>(len: usize) {
+            let max = FixedDec::<T, P>::MAX.to_string().len();
+            let min = FixedDec::<T, P>::MIN.to_string().len();
+            assert_eq!(max.max(min), len);
+            let mut buf = [0u8; 64];
+            assert!(FixedDec::<T, P>::MIN.format_into(&mut buf[..len]).is_some());
+            assert!(FixedDec::<T, P>::MAX.format_into(&mut buf[..len]).is_some());
+        }
+        check::<u8, 0>(FixedDec::<u8, 0>::max_display_len());
+        check::<u8, 2>(FixedDec::<u8, 2>::max_display_len());
+        check::<i8, 1>(FixedDec::<i8, 1>::max_display_len());
+        check::<i8, 2>(FixedDec::<i8, 2>::max_display_len());
+        check::<u16, 3>(FixedDec::<u16, 3>::max_display_len());
+        check::<i32, 9>(FixedDec::<i32, 9>::max_display_len());
+        check::<u64, 19>(FixedDec::<u64, 19>::max_display_len());
+        check::<i128, 0>(FixedDec::<i128, 0>::max_display_len());
+        check::<u128, 38>(FixedDec::<u128, 38>::max_display_len());
+        assert_eq!(FixedDec::<u128, 38>::max_display_len(), 40);
+        assert_eq!(FixedDec::<u32, 0>::max_display_len(), 12);
+    }
+
+    #[test]
+    fn split() {
+        let total = FixedDec::<u32, 2>::from_str("10.00").unwrap();
+        let parts = total.split(3).unwrap();
+        assert_eq!(
+            parts,
+            ["3.34", "3.33", "3.33"].map(|s| FixedDec::from_str(s).unwrap())
+        );
+
+        let check = |v: FixedDec<i64, 2>, n: u32| {
+            let parts = v.split(n).unwrap();
+            assert_eq!(parts.len(), n as usize);
+            assert_eq!(parts.iter().fold(FixedDec::new(0), |acc, x| acc + x), v);
+            let min = parts.iter().min().unwrap();
+            let max = parts.iter().max().unwrap();
+            assert!(max.value() - min.value() <= 1);
+            // the biggest magnitudes first
+            assert!(
+                parts
+                    .windows(2)
+                    .all(|w| w[0].abs_diff(FixedDec::new(0)) >= w[1].abs_diff(FixedDec::new(0)))
+            );
+        };
+        check(FixedDec::new(1000), 3);
+        check(FixedDec::new(-1000), 3);
+        check(FixedDec::new(1000), 7);
+        check(FixedDec::new(5), 7);
+        check(FixedDec::new(-5), 7);
+        check(FixedDec::new(0), 4);
+        check(FixedDec::new(1), 1);
+        check(FixedDec::MAX, 10);
+        check(FixedDec::MIN, 10);
+
+        let parts = FixedDec::<i32, 2>::new(-1000).split(3).unwrap();
+        assert_eq!(
+            parts,
+            [
+                FixedDec::new(-334),
+                FixedDec::new(-333),
+                FixedDec::new(-333)
+            ]
+        );
+
+        assert_eq!(total.split(0), None);
+        assert_eq!(FixedDec::<u8, 1>::new(100).split(256), None);
+        assert_eq!(FixedDec::<u8, 1>::new(100).split(255).unwrap().len(), 255);
+    }
+
+    #[test]
+    fn div_rem() {
+        let x1 = FixedDec::<u32, 2>::new(1000);
+        let i1 = FixedDec::<i32, 2>::new(-1000);
+
+        assert_eq!(x1.div_rem(3), Some((FixedDec::new(333), FixedDec::new(1))));
+        assert_eq!(x1.div_rem(4), Some((FixedDec::new(250), FixedDec::new(0))));
+        assert_eq!(x1.div_rem(0), None);
+        assert_eq!(
+            i1.div_rem(3),
+            Some((FixedDec::new(-333), FixedDec::new(-1)))
+        );
+        assert_eq!(
+            i1.div_rem(-3),
+            Some((FixedDec::new(333), FixedDec::new(-1)))
+        );
+        assert_eq!(FixedDec::<i8, 0>::MIN.div_rem(-1), None);
+
+        for (v, d) in [
+            (1000, 7),
+            (-1000, 7),
+            (999, -13),
+            (i32::MIN, 3),
+            (i32::MAX, -2),
+        ] {
+            let x = FixedDec::<i32, 2>::new(v);
+            let (q, r) = x.div_rem(d).unwrap();
+            assert_eq!(q * d + r, x);
+        }
+    }
+
+    #[test]
+    fn from_str_const() {
+        const X1: FixedDec<u64, 4> = FixedDec::<u64, 4>::from_str_const("0.0525");
+        const X2: FixedDec<i32, 2> = FixedDec::<i32, 2>::from_str_const("-12.5");
+        const X3: FixedDec<i8, 2> = FixedDec::<i8, 2>::from_str_const("-1.28");
+        const X4: FixedDec<u8, 0> = FixedDec::<u8, 0>::from_str_const("255");
+        const X5: FixedDec<u32, 2> = FixedDec::<u32, 2>::from_str_const("7.");
+        const X6: FixedDec<u32, 2> = FixedDec::<u32, 2>::from_str_const(".5");
+
+        assert_eq!(X1, FixedDec::new(525));
+        assert_eq!(X2, FixedDec::new(-1250));
+        assert_eq!(X3, FixedDec::MIN);
+        assert_eq!(X4, FixedDec::MAX);
+        assert_eq!(X5, FixedDec::new(700));
+        assert_eq!(X6, FixedDec::new(50));
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_str_const_invalid() {
+        FixedDec::<u32, 2>::from_str_const("1.234");
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_str_const_negative_unsigned() {
+        FixedDec::<u32, 2>::from_str_const("-1.23");
+    }
+
+    #[test]
+    fn checked_sum() {
+        let items = [
+            FixedDec::<u8, 1>::new(100),
+            FixedDec::new(100),
+            FixedDec::new(55),
+        ];
+        assert_eq!(FixedDec::checked_sum(&items), Some(FixedDec::MAX));
+        assert_eq!(FixedDec::checked_sum(&items[..1]), Some(FixedDec::new(100)));
+        assert_eq!(FixedDec::<u8, 1>::checked_sum(&[]), Some(FixedDec::new(0)));
+
+        let overflow = [
+            FixedDec::<u8, 1>::new(200),
+            FixedDec::new(56),
+            FixedDec::new(0),
+        ];
+        assert_eq!(FixedDec::checked_sum(&overflow), None);
+
+        let signed = [
+            FixedDec::<i8, 1>::new(100),
+            FixedDec::new(27),
+            FixedDec::new(-100),
+        ];
+        assert_eq!(FixedDec::checked_sum(&signed), Some(FixedDec::new(27)));
+        // the intermediate result overflow, even if the total would fit
+        let signed = [
+            FixedDec::<i8, 1>::new(100),
+            FixedDec::new(28),
+            FixedDec::new(-100),
+        ];
+        assert_eq!(FixedDec::checked_sum(&signed), None);
+    }
+
+    #[test]
+    fn weighted_sum() {
+        let values = [
+            FixedDec::<i32, 2>::new(1050),
+            FixedDec::new(-299),
+            FixedDec::new(1),
+        ];
+        // 10.50 * 3 - 2.99 * 10 + 0.01 * 7 = 31.50 - 29.90 + 0.07 = 1.67
+        assert_eq!(
+            FixedDec::weighted_sum(&values, &[3, 10, 7]),
+            Some(FixedDec::new(167))
+        );
+        assert_eq!(FixedDec::weighted_sum(&values, &[3, 10]), None);
+        assert_eq!(
+            FixedDec::<i32, 2>::weighted_sum(&[], &[]),
+            Some(FixedDec::new(0))
+        );
+
+        // intermediate products overflow T but not the result
+        let values = [FixedDec::<u8, 1>::new(200), FixedDec::new(100)];
+        assert_eq!(
+            FixedDec::weighted_sum(&values, &[1, 0]),
+            Some(FixedDec::new(200))
+        );
+        let values = [FixedDec::<i8, 1>::new(100), FixedDec::new(-100)];
+        assert_eq!(
+            FixedDec::weighted_sum(&values, &[3, 2]),
+            Some(FixedDec::new(100))
+        );
+        assert_eq!(FixedDec::weighted_sum(&values, &[3, 1]), None);
+    }
+
+    #[test]
+    fn apply_rate() {
+        use RoundingMode::*;
+
+        let usd = FixedDec::<u64, 2>::from_str("100.00").unwrap();
+        let rate = FixedDec::<u64, 4>::from_str("0.8542").unwrap();
+        let eur = FixedDec::<u64, 2>::from_str("85.42").unwrap();
+        assert_eq!(usd.apply_rate(rate, HalfEven), Some(eur));
+
+        // rounding of the extra digits
+        let amount = FixedDec::<u64, 2>::from_str("12.34").unwrap();
+        assert_eq!(amount.apply_rate(rate, HalfUp), Some(FixedDec::new(10_54)));
+        assert_eq!(
+            amount.apply_rate(rate, TowardZero),
+            Some(FixedDec::new(10_54))
+        );
+        assert_eq!(amount.apply_rate(rate, Ceiling), Some(FixedDec::new(10_55)));
+        let neg = FixedDec::<i64, 2>::new(-12_34);
+        let rate = FixedDec::<i64, 1>::new(5);
+        assert_eq!(neg.apply_rate(rate, HalfUp), Some(FixedDec::new(-6_17)));
+        assert_eq!(neg.apply_rate(rate, HalfEven), Some(FixedDec::new(-6_17)));
+        assert_eq!(
+            neg.apply_rate(FixedDec::<i64, 2>::new(5), HalfEven),
+            Some(FixedDec::new(-62))
+        );
+
+        // intermediate product overflowing T
+        let big = FixedDec::<u32, 2>::new(4_000_000_000);
+        let half = FixedDec::<u32, 3>::new(500);
+        assert_eq!(
+            big.apply_rate(half, HalfUp),
+            Some(FixedDec::new(2_000_000_000))
+        );
+        assert_eq!(big.apply_rate(FixedDec::<u32, 0>::new(2), HalfUp), None);
+    }
+
+    #[test]
+    fn div_round() {
+        use RoundingMode::*;
+
+        let bill = FixedDec::<u32, 2>::from_str("10.00").unwrap();
+        let share = bill.div_round(3, HalfUp).unwrap();
+        assert_eq!(share, FixedDec::from_str("3.33").unwrap());
+        let (truncated, leftover) = bill.div_rem(3).unwrap();
+        assert_eq!(share, truncated);
+        assert_eq!(share * 3 + leftover, bill);
+
+        let f = FixedDec::<u32, 2>::new(200);
+        assert_eq!(f.div_round(3, HalfUp), Some(FixedDec::new(67)));
+        assert_eq!(f.div_round(3, TowardZero), Some(FixedDec::new(66)));
+        assert_eq!(
+            FixedDec::<u32, 2>::new(5).div_round(2, HalfEven),
+            Some(FixedDec::new(2))
+        );
+        assert_eq!(
+            FixedDec::<u32, 2>::new(7).div_round(2, HalfEven),
+            Some(FixedDec::new(4))
+        );
+
+        let n = FixedDec::<i32, 2>::new(-200);
+        assert_eq!(n.div_round(3, HalfUp), Some(FixedDec::new(-67)));
+        assert_eq!(n.div_round(3, Ceiling), Some(FixedDec::new(-66)));
+        assert_eq!(n.div_round(-3, Floor), Some(FixedDec::new(66)));
+
+        assert_eq!(f.div_round(0, HalfUp), None);
+        assert_eq!(FixedDec::<i8, 1>::MIN.div_round(-1, HalfUp), None);
+    }
+
+    #[test]
+    fn product() {
+        let f = FixedDec::<u64, 4>::from_str("1.1").unwrap();
+        let factors = [f, f, f];
+        let expected = FixedDec::<u64, 4>::from_str("1.3310").unwrap();
+        assert_eq!(factors.iter().product::<FixedDec<u64, 4>>(), expected);
+        assert_eq!(factors.into_iter().product::<FixedDec<u64, 4>>(), expected);
+        assert_eq!(FixedDec::try_product(factors), Some(expected));
+
+        let empty: [FixedDec<i32, 2>; 0] = [];
+        assert_eq!(FixedDec::try_product(empty), Some(FixedDec::new(100)));
+        let signs = [
+            FixedDec::<i32, 2>::new(-50),
+            FixedDec::new(-250),
+            FixedDec::new(-1000),
+        ];
+        assert_eq!(FixedDec::try_product(signs), Some(FixedDec::new(-1250)));
+
+        let big = [FixedDec::<u8, 1>::new(200), FixedDec::new(20)];
+        assert_eq!(FixedDec::try_product(big), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "product overflow")]
+    fn product_overflow() {
+        let big = [FixedDec::<u8, 1>::new(200), FixedDec::new(20)];
+        let _: FixedDec<u8, 1> = big.iter().product();
+    }
+
+    #[test]
+    fn checked_pow() {
+        // 1.1^10 = 2.5937424601, with the truncation error bounded by a few units
+        let x = FixedDec::<u64, 6>::from_str("1.1").unwrap();
+        let known = FixedDec::<u64, 6>::from_str("2.593742").unwrap();
+        let pow = x.checked_pow(10).unwrap();
+        assert!(pow <= known);
+        assert!(known.checked_sub(pow).unwrap().value() < 10);
+
+        // exact when no digit is dropped
+        let x = FixedDec::<i32, 2>::new(-150);
+        assert_eq!(x.checked_pow(1), Some(x));
+        assert_eq!(x.checked_pow(2), Some(FixedDec::new(225)));
+        assert_eq!(x.checked_pow(3), Some(FixedDec::new(-337)));
+        assert_eq!(
+            FixedDec::<u8, 2>::new(0).checked_pow(0),
+            Some(FixedDec::new(100))
+        );
+
+        // the intermediate products don't overflow T
+        let x = FixedDec::<u32, 4>::new(20_000);
+        assert_eq!(x.checked_pow(18), Some(FixedDec::new(2_621_440_000)));
+        assert_eq!(x.checked_pow(19), None);
+
+        // no overflow on small values, and no unnecessary last square
+        let half = FixedDec::<u8, 2>::new(50);
+        assert_eq!(half.checked_pow(u32::MAX), Some(FixedDec::new(0)));
+        let two = FixedDec::<u8, 0>::new(2);
+        assert_eq!(two.checked_pow(7), Some(FixedDec::new(128)));
+    }
+
+    #[test]
+    fn trailing_zeros() {
+        let x = FixedDec::<u32, 3>::from_str("1.500").unwrap();
+        assert_eq!(x.trailing_zeros_fractional(), 2);
+        assert_eq!(x.normalized_precision(), 1);
+        assert!(x.fits_precision::<1>());
+        assert!(!x.fits_precision::<0>());
+
+        assert_eq!(FixedDec::<u32, 3>::new(1234).trailing_zeros_fractional(), 0);
+        assert_eq!(FixedDec::<u32, 3>::new(1234).normalized_precision(), 3);
+        assert_eq!(FixedDec::<u32, 3>::new(0).trailing_zeros_fractional(), 3);
+        assert_eq!(FixedDec::<u32, 3>::new(0).normalized_precision(), 0);
+        assert_eq!(
+            FixedDec::<u32, 3>::new(10_000).trailing_zeros_fractional(),
+            3
+        );
+        assert_eq!(FixedDec::<u32, 0>::new(100).trailing_zeros_fractional(), 0);
+        assert_eq!(FixedDec::<i32, 4>::new(-1_2300).normalized_precision(), 2);
+        assert_eq!(FixedDec::<i8, 1>::MIN.normalized_precision(), 1);
+        assert_eq!(FixedDec::<i64, 3>::new(i64::MIN).normalized_precision(), 3);
+    }
+
+    #[test]
+    fn sign() {
+        use core::cmp::Ordering;
+
+        assert_eq!(FixedDec::<i32, 2>::new(150).sign(), Ordering::Greater);
+        assert_eq!(FixedDec::<i32, 2>::new(-1).sign(), Ordering::Less);
+        assert_eq!(FixedDec::<i32, 2>::new(0).sign(), Ordering::Equal);
+        assert_eq!(FixedDec::<i8, 1>::MIN.sign(), Ordering::Less);
+        assert_eq!(FixedDec::<u8, 1>::MAX.sign(), Ordering::Greater);
+        assert_eq!(FixedDec::<u8, 1>::MIN.sign(), Ordering::Equal);
 
-    fn div(self, rhs: T) -> Self::Output {
-        Self(self.0 / rhs)
+        assert!(FixedDec::<i32, 2>::new(0).is_zero());
+        assert!(!FixedDec::<i32, 2>::new(-1).is_zero());
+        assert!(FixedDec::<u64, 4>::MIN.is_zero());
     }
-}
 
-impl<T: Number, const P: u32> DivAssign<T> for FixedDec<T, P> {
-    fn div_assign(&mut self, rhs: T) {
-        self.0 /= rhs
+    #[test]
+    fn mul_fixed_round() {
+        use RoundingMode::*;
+
+        // 0.15 * 0.15 = 0.0225, whose dropped digits are below the half at P=2
+        let a = FixedDec::<u32, 2>::new(15);
+        assert_eq!(a.mul_fixed_round(a, HalfEven), Some(FixedDec::new(2)));
+        assert_eq!(a.mul_fixed_round(a, HalfUp), Some(FixedDec::new(2)));
+        assert_eq!(a.mul_fixed_round(a, Ceiling), Some(FixedDec::new(3)));
+
+        // and exactly on the half at P=3
+        let a = FixedDec::<u32, 3>::new(150);
+        assert_eq!(a.mul_fixed_round(a, HalfEven), Some(FixedDec::new(22)));
+        assert_eq!(a.mul_fixed_round(a, HalfUp), Some(FixedDec::new(23)));
+        assert_eq!(a.checked_mul_fixed(a), Some(FixedDec::new(22)));
+
+        let n = FixedDec::<i32, 3>::new(-150);
+        let p = FixedDec::<i32, 3>::new(150);
+        assert_eq!(n.mul_fixed_round(p, HalfUp), Some(FixedDec::new(-23)));
+        assert_eq!(n.mul_fixed_round(p, HalfEven), Some(FixedDec::new(-22)));
+        assert_eq!(n.mul_fixed_round(n, Floor), Some(FixedDec::new(22)));
+
+        // intermediate product overflowing T but not the result
+        let big = FixedDec::<u32, 4>::new(4_000_000_000);
+        let half = FixedDec::<u32, 4>::new(5_000);
+        assert_eq!(
+            big.checked_mul_fixed(half),
+            Some(FixedDec::new(2_000_000_000))
+        );
+        assert_eq!(big.checked_mul_fixed(big), None);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn mul_widening_into() {
+        let f = FixedDec::<u32, 2>::new(u32::MAX);
+        assert_eq!(f.checked_mul(1_000_000), None);
+        assert_eq!(
+            f.mul_widening::<u64>(1_000_000),
+            Some(FixedDec::new(4_294_967_295_000_000))
+        );
+        assert_eq!(
+            f.mul_widening::<u128>(u32::MAX),
+            Some(FixedDec::new(u128::from(u32::MAX) * u128::from(u32::MAX)))
+        );
+        assert_eq!(f.mul_widening::<u32>(2), None);
+        assert_eq!(f.mul_widening::<u32>(1), Some(f));
+
+        let i = FixedDec::<i16, 1>::new(-30_000);
+        assert_eq!(i.mul_widening::<i32>(3), Some(FixedDec::new(-90_000)));
+
+        // U can be wider than T::Wide, and gives the same result when it fits in both
+        let r = i.mul_widening::<i64>(-i16::MAX);
+        assert_eq!(r, Some(FixedDec::new(983_010_000)));
+        assert_eq!(
+            i.checked_mul_widening(-i16::MAX),
+            Some(FixedDec::new(983_010_000))
+        );
+
+        let m = FixedDec::<u64, 0>::MAX;
+        assert_eq!(
+            m.mul_widening::<u128>(u64::MAX).map(FixedDec::value),
+            Some(u128::from(u64::MAX) * u128::from(u64::MAX))
+        );
+        assert_eq!(
+            FixedDec::<i64, 0>::MIN.mul_widening::<i128>(i64::MIN),
+            Some(FixedDec::new(1 << 126))
+        );
+    }
 
     #[test]
-    fn integral_fractional() {
-        let x1: FixedDec<u32, 3> = FixedDec::new(1000);
-        let x2: FixedDec<u32, 4> = FixedDec::new(1000);
-        let x3: FixedDec<u32, 3> = FixedDec::new(1234);
-        let i1: FixedDec<i32, 3> = FixedDec::new(-1234);
+    fn to_ratio() {
+        assert_eq!(
+            FixedDec::<u32, 2>::from_str("1.50").unwrap().to_ratio(),
+            Some((3, 2))
+        );
+        assert_eq!(
+            FixedDec::<u32, 2>::from_str("0.25").unwrap().to_ratio(),
+            Some((1, 4))
+        );
+        assert_eq!(
+            FixedDec::<u32, 2>::from_str("0.07").unwrap().to_ratio(),
+            Some((7, 100))
+        );
+        assert_eq!(
+            FixedDec::<u32, 2>::from_str("12").unwrap().to_ratio(),
+            Some((12, 1))
+        );
+        assert_eq!(FixedDec::<u32, 2>::new(0).to_ratio(), Some((0, 1)));
+        assert_eq!(FixedDec::<u32, 0>::new(42).to_ratio(), Some((42, 1)));
+        assert_eq!(
+            FixedDec::<i32, 2>::from_str("-1.50").unwrap().to_ratio(),
+            Some((-3, 2))
+        );
+        assert_eq!(
+            FixedDec::<i64, 4>::MIN.to_ratio(),
+            Some((i64::MIN / 16, 625))
+        );
+        assert_eq!(FixedDec::<i8, 2>::MIN.to_ratio(), Some((-32, 25)));
+        assert_eq!(
+            FixedDec::<u128, 38>::MAX.to_ratio(),
+            Some((u128::MAX / 5, 2 * 10u128.pow(37)))
+        );
 
-        assert_eq!(x1.integral(), 1);
-        assert_eq!(x1.fractional(), 0);
+        for v in [1i32, -1, 3, 20, -125, 1000, i32::MAX, i32::MIN] {
+            let (num, den) = FixedDec::<i32, 3>::new(v).to_ratio().unwrap();
+            assert_eq!(i64::from(num) * 1000, i64::from(v) * i64::from(den));
+        }
 
-        assert_eq!(x2.integral(), 0);
-        assert_eq!(x2.fractional(), 1000);
+        // precision too big for the backing type
+        let f = FixedDec::<u16, 3>::new(123).try_into::<u8>().unwrap();
+        assert_eq!(f.to_ratio(), None);
+    }
 
-        assert_eq!(x3.integral(), 1);
-        assert_eq!(x3.fractional(), 234);
+    #[test]
+    fn basis_points() {
+        // round trip at precisions below, at and above 4
+        for bps in [0, 1, 50, 9_999, 10_000, 123_456, -1, -250] {
+            let f = FixedDec::<i64, 4>::from_basis_points(bps).unwrap();
+            assert_eq!(f.value(), bps);
+            assert_eq!(f.to_basis_points(), Some(bps));
+            let f = FixedDec::<i64, 8>::from_basis_points(bps).unwrap();
+            assert_eq!(f.value(), bps * 10_000);
+            assert_eq!(f.to_basis_points(), Some(bps));
+        }
+        for bps in [0, 100, 2_500, -300] {
+            let f = FixedDec::<i32, 2>::from_basis_points(bps).unwrap();
+            assert_eq!(f.to_basis_points(), Some(bps));
+        }
+        assert_eq!(
+            FixedDec::<u32, 4>::from_basis_points(50),
+            FixedDec::from_str("0.0050")
+        );
+        assert_eq!(
+            FixedDec::<u32, 4>::from_basis_points(10_000),
+            Some(FixedDec::ONE)
+        );
 
-        assert_eq!(i1.integral(), -1);
-        assert_eq!(i1.fractional(), 234);
+        // not representable at the precision
+        assert_eq!(FixedDec::<i32, 2>::from_basis_points(-5), None);
+        assert_eq!(FixedDec::<u8, 0>::from_basis_points(100), None);
+        assert_eq!(
+            FixedDec::<u8, 0>::from_basis_points(0),
+            Some(FixedDec::new(0))
+        );
+        assert_eq!(FixedDec::<u32, 6>::new(1).to_basis_points(), None);
+
+        // overflow
+        assert_eq!(FixedDec::<u16, 6>::from_basis_points(700), None);
+        assert_eq!(FixedDec::<u32, 8>::from_basis_points(430_000), None);
+        assert_eq!(FixedDec::<u32, 0>::new(500_000).to_basis_points(), None);
+        assert_eq!(FixedDec::<u8, 0>::new(1).to_basis_points(), None);
+        assert_eq!(FixedDec::<u8, 0>::new(0).to_basis_points(), Some(0));
     }
 
     #[test]
-    fn formatting() {
-        let x1: FixedDec<u32, 3> = FixedDec::new(1000);
-        let x2: FixedDec<u32, 4> = FixedDec::new(1000);
-        let x3: FixedDec<u32, 3> = FixedDec::new(1234);
-        let x4: FixedDec<u32, 2> = FixedDec::new(123456);
+    fn from_parts_scaled() {
+        type F = FixedDec<i64, 3>;
 
-        assert_eq!(alloc::format!("{}", x1), "1.000");
-        assert_eq!(alloc::format!("{}", x2), "0.1000");
-        assert_eq!(alloc::format!("{}", x3), "1.234");
-        assert_eq!(alloc::format!("{}", x4), "1234.56");
+        // frac_digits < P
+        assert_eq!(F::from_parts_scaled(12, 5, 1), F::from_str("12.5"));
+        assert_eq!(F::from_parts_scaled(12, 5, 2), F::from_str("12.05"));
+        assert_eq!(F::from_parts_scaled(12, 0, 0), F::from_str("12"));
+        assert_eq!(F::from_parts_scaled(-12, -5, 1), F::from_str("-12.5"));
+        assert_eq!(F::from_parts_scaled(0, -5, 1), F::from_str("-0.5"));
+        assert_eq!(F::from_parts_scaled(12, 10, 1), None);
+        assert_eq!(F::from_parts_scaled(12, -10, 2), None);
+
+        // frac_digits == P
+        assert_eq!(F::from_parts_scaled(12, 345, 3), F::from_parts(12, 345));
+
+        // frac_digits > P, the extra digits are truncated
+        assert_eq!(F::from_parts_scaled(12, 34567, 5), F::from_str("12.345"));
+        assert_eq!(F::from_parts_scaled(-12, -34567, 5), F::from_str("-12.345"));
+        assert_eq!(F::from_parts_scaled(12, 9, 5), F::from_str("12"));
+        assert_eq!(F::from_parts_scaled(12, 100_000, 5), None);
+        assert_eq!(F::from_parts_scaled(12, -9, 5), None);
+        assert_eq!(F::from_parts_scaled(-12, 9, 5), None);
+        assert_eq!(F::from_parts_scaled(-12, 5, 1), None);
+        assert_eq!(F::from_parts_scaled(-12, -9, 5), F::from_str("-12"));
+        assert_eq!(F::from_parts_scaled(-12, 0, 5), F::from_str("-12"));
+        assert_eq!(F::from_parts_scaled(0, 9, 5), F::from_str("0"));
+        // 10^30 doesn't fit in i64, so any fractional part is valid
+        assert_eq!(F::from_parts_scaled(1, i64::MAX, 30), F::from_str("1"));
+
+        // overflow of the backing type
+        assert_eq!(FixedDec::<u8, 1>::from_parts_scaled(25, 6, 1), None);
+        assert_eq!(
+            FixedDec::<u8, 1>::from_parts_scaled(25, 59, 2),
+            Some(FixedDec::new(255))
+        );
     }
 
     #[test]
-    fn rounding() {
-        let x1: FixedDec<u32, 3> = FixedDec::new(1000);
-        let x2: FixedDec<u32, 4> = FixedDec::new(1000);
-        let x3: FixedDec<u32, 3> = FixedDec::new(1234);
-        let x4: FixedDec<u32, 2> = FixedDec::new(123456);
+    fn set_precision_exact() {
+        let f = FixedDec::<u32, 3>::from_str("1.200").unwrap();
+        assert_eq!(f.set_precision_exact::<1>(), FixedDec::from_str("1.2"));
+        assert_eq!(f.set_precision_exact::<2>(), FixedDec::from_str("1.20"));
+        assert_eq!(f.set_precision_exact::<0>(), None);
+        assert_eq!(f.set_precision_exact::<3>(), Some(f));
+        assert_eq!(f.set_precision_exact::<5>(), FixedDec::from_str("1.2"));
 
-        assert_eq!(x1.round_at(2), x1);
-        assert_eq!(x2.round_at(2), x2);
-        assert_eq!(x3.round_at(2), FixedDec::new(1230));
-        assert_eq!(x4.round_at(1), FixedDec::new(123450));
-        assert_eq!(x4.round_at(3), FixedDec::new(123456));
+        let f = FixedDec::<u32, 3>::from_str("1.234").unwrap();
+        assert_eq!(f.set_precision::<1>(), FixedDec::from_str("1.2"));
+        assert_eq!(f.set_precision_exact::<1>(), None);
+
+        let f = FixedDec::<i32, 3>::from_str("-1.230").unwrap();
+        assert_eq!(f.set_precision_exact::<2>(), FixedDec::from_str("-1.23"));
+        assert_eq!(f.set_precision_exact::<1>(), None);
+
+        // overflow when increasing the precision
+        assert_eq!(FixedDec::<u8, 0>::new(3).set_precision_exact::<2>(), None);
     }
 
     #[test]
-    fn from_str() {
-        let x0 = FixedDec::<u32, 0>::new(1234);
-        let x1 = FixedDec::<u32, 3>::new(1234);
-        let x2 = FixedDec::<u32, 3>::new(10234);
-        let x3 = FixedDec::<u32, 4>::new(10234);
-        let x4 = FixedDec::<u32, 4>::new(12340);
+    fn next_up_down() {
+        let f = FixedDec::<i32, 3>::from_str("1.500").unwrap();
+        assert_eq!(f.next_up(), FixedDec::from_str("1.501"));
+        assert_eq!(f.next_down(), FixedDec::from_str("1.499"));
+        assert_eq!(f.next_up().and_then(FixedDec::next_down), Some(f));
 
-        assert_eq!(FixedDec::from_str("1234"), Some(x0));
-        assert_eq!(FixedDec::from_str("1.234"), Some(x1));
-        assert_eq!(FixedDec::from_str("10.234"), Some(x2));
-        assert_eq!(FixedDec::from_str("1.0234"), Some(x3));
-        assert_eq!(FixedDec::from_str("1.02345"), Some(x3));
-        assert_eq!(FixedDec::from_str("1.234"), Some(x4));
+        let zero = FixedDec::<i32, 3>::new(0);
+        assert_eq!(zero.next_down(), FixedDec::from_str("-0.001"));
+        assert_eq!(zero.next_up(), FixedDec::from_str("0.001"));
+
+        assert_eq!(FixedDec::<i32, 3>::MAX.next_up(), None);
+        assert_eq!(FixedDec::<i32, 3>::MIN.next_down(), None);
+        assert_eq!(FixedDec::<u8, 1>::MAX.next_up(), None);
+        assert_eq!(FixedDec::<u8, 1>::MIN.next_down(), None);
+        assert_eq!(FixedDec::<u8, 1>::MAX.next_down(), Some(FixedDec::new(254)));
+        assert_eq!(FixedDec::<u8, 1>::MIN.next_up(), Some(FixedDec::new(1)));
+    }
+
+    #[test]
+    fn add_sub_integral() {
+        let f = FixedDec::<i32, 3>::from_str("1.250").unwrap();
+        assert_eq!(f.checked_add_integral(5), FixedDec::from_str("6.250"));
+        assert_eq!(f.checked_add_integral(-5), FixedDec::from_str("-3.750"));
+        assert_eq!(f.checked_sub_integral(5), FixedDec::from_str("-3.750"));
+        assert_eq!(f.checked_sub_integral(-5), FixedDec::from_str("6.250"));
+        assert_eq!(f.checked_add_integral(0), Some(f));
+        assert_eq!(
+            f.checked_add_integral(5),
+            f.checked_add(FixedDec::from_integral(5).unwrap())
+        );
+
+        // overflow
+        assert_eq!(f.checked_add_integral(2_147_483), None);
+        assert_eq!(
+            f.checked_add_integral(2_147_482),
+            FixedDec::from_str("2147483.250")
+        );
+        assert_eq!(f.checked_sub_integral(2_147_485), None);
+        assert_eq!(FixedDec::<i32, 3>::MAX.checked_add_integral(1), None);
+        assert_eq!(FixedDec::<u8, 1>::new(5).checked_sub_integral(1), None);
+        assert_eq!(
+            FixedDec::<u8, 1>::new(5).checked_add_integral(25),
+            Some(FixedDec::MAX)
+        );
+        assert_eq!(FixedDec::<u8, 1>::new(5).checked_add_integral(26), None);
+
+        // the scaled amount doesn't fit in T, but the result does
+        let f = FixedDec::<i8, 1>::from_str("-12.0").unwrap();
+        assert!(FixedDec::<i8, 1>::from_integral(13).is_none());
+        assert_eq!(f.checked_add_integral(13), FixedDec::from_str("1.0"));
+        let f = FixedDec::<i8, 1>::from_str("12.0").unwrap();
+        assert_eq!(f.checked_sub_integral(13), FixedDec::from_str("-1.0"));
+    }
+
+    #[test]
+    fn lerp() {
+        let zero = FixedDec::<u32, 2>::from_str("0.00").unwrap();
+        let ten = FixedDec::<u32, 2>::from_str("10.00").unwrap();
+        let half = FixedDec::<u32, 2>::from_str("0.50").unwrap();
+        let one = FixedDec::<u32, 2>::from_str("1.00").unwrap();
+        assert_eq!(zero.lerp(ten, half), FixedDec::from_str("5.00"));
+        assert_eq!(ten.lerp(zero, half), FixedDec::from_str("5.00"));
+        assert_eq!(zero.lerp(ten, zero), Some(zero));
+        assert_eq!(zero.lerp(ten, one), Some(ten));
+        assert_eq!(ten.lerp(zero, one), Some(zero));
+
+        // the step is truncated toward self
+        let third = FixedDec::<u32, 2>::new(33);
+        assert_eq!(zero.lerp(FixedDec::new(1), third), Some(zero));
+        assert_eq!(ten.lerp(zero, third), FixedDec::from_str("6.70"));
+
+        // extrapolation
+        let two = FixedDec::<u32, 2>::new(200);
+        assert_eq!(zero.lerp(ten, two), FixedDec::from_str("20.00"));
+        assert_eq!(
+            ten.lerp(FixedDec::new(900), two),
+            FixedDec::from_str("8.00")
+        );
+        assert_eq!(ten.lerp(zero, two), None);
+        assert_eq!(zero.lerp(FixedDec::MAX, two), None);
+
+        // the difference is computed in the wider type
+        let min = FixedDec::<i32, 2>::MIN;
+        let max = FixedDec::<i32, 2>::MAX;
+        let half = FixedDec::<i32, 2>::new(50);
+        assert!(min.checked_sub(max).is_none());
+        assert_eq!(min.lerp(max, half), Some(FixedDec::new(-1)));
+        assert_eq!(max.lerp(min, half), Some(FixedDec::new(0)));
+        let back = FixedDec::<i32, 2>::new(-100);
+        assert_eq!(
+            FixedDec::<i32, 2>::new(100).lerp(FixedDec::new(300), back),
+            Some(FixedDec::new(-100))
+        );
+    }
+
+    #[test]
+    fn midpoint() {
+        let max = FixedDec::<u64, 4>::MAX;
+        let below = FixedDec::<u64, 4>::new(u64::MAX - 10);
+        assert!(max.checked_add(below).is_none());
+        assert_eq!(max.midpoint(below), FixedDec::new(u64::MAX - 5));
+        assert_eq!(below.midpoint(max), FixedDec::new(u64::MAX - 5));
+        assert_eq!(max.midpoint(max), max);
+
+        let a = FixedDec::<i32, 2>::new(-101);
+        let b = FixedDec::<i32, 2>::new(0);
+        assert_eq!(a.midpoint(b).value(), -50);
+        let min = FixedDec::<i32, 2>::MIN;
+        assert_eq!(min.midpoint(FixedDec::MAX).value(), 0);
+    }
+
+    #[test]
+    fn integral_range() {
+        assert!(FixedDec::<u8, 0>::has_integral_range());
+        assert!(FixedDec::<u8, 2>::has_integral_range());
+        assert!(!FixedDec::<u8, 3>::has_integral_range());
+        assert!(!FixedDec::<i8, 3>::has_integral_range());
+        assert!(FixedDec::<u128, 38>::has_integral_range());
+        assert!(!FixedDec::<u128, 39>::has_integral_range());
+        const { assert!(!FixedDec::<u8, 3>::has_integral_range()) };
+        assert_eq!(FixedDec::<u8, 3>::new_checked(0), None);
+
+        assert_eq!(FixedDec::<u8, 0>::max_integral(), 255);
+        assert_eq!(FixedDec::<u8, 2>::max_integral(), 2);
+        assert_eq!(FixedDec::<i8, 2>::max_integral(), 1);
+        assert_eq!(FixedDec::<i8, 2>::min_integral(), -1);
+        assert_eq!(FixedDec::<u32, 3>::max_integral(), 4_294_967);
+        assert_eq!(FixedDec::<i64, 9>::min_integral(), -9_223_372_036);
+        assert_eq!(FixedDec::<u128, 38>::max_integral(), 3);
+
+        fn check<T: Number, const P: u32>() {
+            let max = FixedDec::<T, P>::max_integral();
+            let min = FixedDec::<T, P>::min_integral();
+            assert!(FixedDec::<T, P>::from_integral(max).is_some());
+            assert!(FixedDec::<T, P>::from_integral(min).is_some());
+            if let Some(above) = max.checked_add(T::ONE) {
+                assert!(FixedDec::<T, P>::from_integral(above).is_none());
+            }
+            if let Some(below) = min.checked_sub(T::ONE) {
+                assert!(FixedDec::<T, P>::from_integral(below).is_none());
+            }
+        }
+        check::<u8, 1>();
+        check::<i8, 2>();
+        check::<i16, 3>();
+        check::<u64, 12>();
+        check::<i128, 20>();
+    }
+
+    #[test]
+    fn from_str_in_range() {
+        let min = FixedDec::<i64, 2>::from_str("-100.00").unwrap();
+        let max = FixedDec::<i64, 2>::from_str("1000000.00").unwrap();
+        let parse = |s| FixedDec::from_str_in_range(s, min, max);
+
+        assert_eq!(parse("12.5"), Some(FixedDec::new(1250)));
+        assert_eq!(parse("1000000"), Some(max));
+        assert_eq!(parse("-100"), Some(min));
+        assert_eq!(parse("0"), Some(FixedDec::new(0)));
+        assert_eq!(parse("1000000.01"), None);
+        assert_eq!(parse("-100.01"), None);
+        assert_eq!(parse("99999999999999999999"), None);
+        assert_eq!(parse("abc"), None);
+
+        // empty range
+        assert_eq!(FixedDec::from_str_in_range("1", max, min), None);
+    }
+
+    #[test]
+    fn from_accounting_str() {
+        type F = FixedDec<i32, 2>;
+        assert_eq!(F::from_accounting_str("(12.34)"), F::from_str("-12.34"));
+        assert_eq!(F::from_accounting_str("(0.5)"), F::from_str("-0.50"));
+        assert_eq!(F::from_accounting_str("(0)"), Some(F::new(0)));
+        assert_eq!(F::from_accounting_str("12.34"), F::from_str("12.34"));
+        assert_eq!(F::from_accounting_str("-12.34"), F::from_str("-12.34"));
+        assert_eq!(
+            FixedDec::<i8, 2>::from_accounting_str("(1.28)"),
+            Some(FixedDec::MIN)
+        );
+        assert_eq!(FixedDec::<i8, 2>::from_accounting_str("(1.29)"), None);
+
+        assert_eq!(F::from_accounting_str("(-12.34)"), None);
+        assert_eq!(F::from_accounting_str("()"), None);
+        assert_eq!(F::from_accounting_str("(12.34"), None);
+        assert_eq!(F::from_accounting_str("12.34)"), None);
+        assert_eq!(F::from_accounting_str("((12.34))"), None);
+        assert_eq!(F::from_accounting_str("( 12.34)"), None);
+
+        // no negative values for unsigned types
+        type U = FixedDec<u32, 2>;
+        assert_eq!(U::from_accounting_str("(12.34)"), None);
+        assert_eq!(U::from_accounting_str("(0)"), None);
+        assert_eq!(U::from_accounting_str("12.34"), Some(U::new(1234)));
+    }
+
+    #[test]
+    fn from_str_trailing_sign() {
+        type F = FixedDec<i32, 2>;
+        assert_eq!(F::from_str_trailing_sign("12.34-"), F::from_str("-12.34"));
+        assert_eq!(F::from_str_trailing_sign("12.34+"), F::from_str("12.34"));
+        assert_eq!(F::from_str_trailing_sign("12.34"), F::from_str("12.34"));
+        assert_eq!(F::from_str_trailing_sign("-12.34"), F::from_str("-12.34"));
+        assert_eq!(F::from_str_trailing_sign(".5-"), F::from_str("-0.5"));
+        assert_eq!(F::from_str_trailing_sign("0-"), Some(F::new(0)));
+        assert_eq!(
+            FixedDec::<i8, 2>::from_str_trailing_sign("1.28-"),
+            Some(FixedDec::MIN)
+        );
+
+        // leading and trailing signs
+        assert_eq!(F::from_str_trailing_sign("-12.34-"), None);
+        assert_eq!(F::from_str_trailing_sign("-12.34+"), None);
+        assert_eq!(F::from_str_trailing_sign("+12.34-"), None);
+        assert_eq!(F::from_str_trailing_sign("+12.34+"), None);
+        assert_eq!(F::from_str_trailing_sign("-"), None);
+        assert_eq!(F::from_str_trailing_sign("+"), None);
+        assert_eq!(F::from_str_trailing_sign("12.34--"), None);
+        assert_eq!(F::from_str_trailing_sign("12-.34"), None);
+        assert_eq!(F::from_str_trailing_sign("12.34 -"), None);
+
+        type U = FixedDec<u32, 2>;
+        assert_eq!(U::from_str_trailing_sign("12.34+"), Some(U::new(1234)));
+        assert_eq!(U::from_str_trailing_sign("12.34-"), None);
+    }
+
+    #[test]
+    fn from_str_relaxed() {
+        type F = FixedDec<u32, 2>;
+
+        assert_eq!(F::from_str_relaxed("1_000.25"), Some(F::new(100025)));
+        assert_eq!(F::from_str_relaxed(" 3.14 "), Some(F::new(314)));
+        assert_eq!(F::from_str_relaxed("\t1_0\n"), Some(F::new(1000)));
+        assert_eq!(F::from_str_relaxed("1_000_000"), Some(F::new(100000000)));
+        assert_eq!(F::from_str_relaxed("0.2_5"), Some(F::new(25)));
+        assert_eq!(F::from_str_relaxed("1.25"), F::from_str("1.25"));
+
+        assert_eq!(F::from_str_relaxed("_5"), None);
+        assert_eq!(F::from_str_relaxed("5_"), None);
+        assert_eq!(F::from_str_relaxed("1_.5"), None);
+        assert_eq!(F::from_str_relaxed("1._5"), None);
+        assert_eq!(F::from_str_relaxed("1__0"), None);
+        assert_eq!(F::from_str_relaxed("_"), None);
+        assert_eq!(F::from_str_relaxed("1 0"), None);
+    }
+
+    #[test]
+    fn from_str_exact() {
+        type F = FixedDec<u32, 3>;
+
+        assert_eq!(F::from_str_exact("1.234"), Some(F::new(1234)));
+        assert_eq!(F::from_str_exact("1.2"), Some(F::new(1200)));
+        assert_eq!(F::from_str_exact("1."), Some(F::new(1000)));
+        assert_eq!(F::from_str_exact("12"), Some(F::new(12000)));
+        assert_eq!(F::from_str_exact("1.2345"), None);
+        assert_eq!(F::from_str_exact("1.2340"), None);
+        assert_eq!(F::from_str_exact("1.2a"), None);
+        assert_eq!(FixedDec::<u32, 0>::from_str_exact("1.0"), None);
+        assert_eq!(
+            FixedDec::<u32, 0>::from_str_exact("1"),
+            Some(FixedDec::new(1))
+        );
+    }
+
+    #[test]
+    fn mul_widening() {
+        let x1 = FixedDec::<u8, 1>::new(200);
+        let x2 = FixedDec::<i64, 2>::new(i64::MAX);
+        let x3 = FixedDec::<u128, 2>::new(u128::MAX);
+
+        assert_eq!(x1.checked_mul(2), None);
+        assert_eq!(
+            x1.checked_mul_widening(2),
+            Some(FixedDec::<u16, 1>::new(400))
+        );
+        assert_eq!(
+            x1.checked_mul_widening(2).and_then(|x| x.checked_div(4)),
+            Some(FixedDec::new(100))
+        );
+
+        assert_eq!(x2.checked_mul(-3), None);
+        assert_eq!(
+            x2.checked_mul_widening(-3),
+            Some(FixedDec::<i128, 2>::new(i64::MAX as i128 * -3))
+        );
+
+        // no wider type for u128
+        assert_eq!(x3.checked_mul_widening(2), None);
+    }
+
+    #[test]
+    fn sort_key() {
+        use alloc::vec;
+        use alloc::vec::Vec;
+
+        let mut same = vec![
+            FixedDec::<i32, 2>::new(150),
+            FixedDec::new(-3),
+            FixedDec::new(0),
+            FixedDec::new(-250),
+        ];
+        let mut by_key = same.clone();
+        same.sort();
+        by_key.sort_by_key(|v| v.sort_key());
+        assert_eq!(same, by_key);
+
+        // records with amounts at different precisions
+        let a = FixedDec::<i64, 0>::new(2);
+        let b = FixedDec::<i64, 2>::new(-150);
+        let c = FixedDec::<i64, 4>::new(1_9999);
+        let d = FixedDec::<i64, 2>::new(200);
+        let mut records: Vec<(i128, &str)> = vec![
+            (a.normalize_for_sort::<4>().unwrap(), "a"),
+            (b.normalize_for_sort::<4>().unwrap(), "b"),
+            (c.normalize_for_sort::<4>().unwrap(), "c"),
+            (d.normalize_for_sort::<4>().unwrap(), "d"),
+        ];
+        records.sort();
+        let order: Vec<&str> = records.iter().map(|(_, name)| *name).collect();
+        assert_eq!(order, ["b", "c", "a", "d"]);
+
+        assert_eq!(c.normalize_for_sort::<2>(), None);
+        assert_eq!(FixedDec::<u128, 0>::MAX.normalize_for_sort::<1>(), None);
+        assert_eq!(
+            FixedDec::<u64, 0>::MAX.normalize_for_sort::<18>(),
+            Some(u64::MAX as u128 * 10u128.pow(18))
+        );
+    }
+
+    #[test]
+    fn scale() {
+        assert_eq!(FixedDec::<u32, 3>::new(0).scale(), 3);
+        assert_eq!(FixedDec::<i8, 0>::MIN.scale(), 0);
+        assert_eq!(FixedDec::<u128, 38>::MAX.scale(), 38);
+        const SCALE: u32 = FixedDec::<u64, 9>::new(1).scale();
+        assert_eq!(SCALE, 9);
+
+        let dynamic = DynFixedDec::from(FixedDec::<u32, 4>::new(12));
+        assert_eq!(dynamic.precision(), FixedDec::<u32, 4>::new(12).scale());
+    }
+
+    #[test]
+    fn new_checked() {
+        assert_eq!(
+            FixedDec::<u8, 2>::new_checked(255),
+            Some(FixedDec::new(255))
+        );
+        assert_eq!(FixedDec::<u8, 3>::new_checked(1), None);
+        assert_eq!(FixedDec::<i8, 3>::new_checked(-1), None);
+        assert_eq!(FixedDec::<u32, 9>::new_checked(1), Some(FixedDec::new(1)));
+        assert_eq!(FixedDec::<u32, 10>::new_checked(1), None);
+        assert_eq!(FixedDec::<u128, 38>::new_checked(7), Some(FixedDec::new(7)));
+        assert_eq!(FixedDec::<u128, 39>::new_checked(7), None);
+
+        const CHECKED: Option<FixedDec<u16, 5>> = FixedDec::new_checked(1);
+        assert!(CHECKED.is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_precision_too_large() {
+        let _ = FixedDec::<u8, 3>::new(1);
+    }
+
+    #[test]
+    fn trunc() {
+        let p = FixedDec::<i32, 2>::from_str("12.34").unwrap();
+        let n = FixedDec::<i32, 2>::from_str("-12.34").unwrap();
+        assert_eq!(p.trunc(), FixedDec::from_str("12.00").unwrap());
+        assert_eq!(n.trunc(), FixedDec::from_str("-12.00").unwrap());
+        assert_eq!(FixedDec::<i32, 2>::new(-99).trunc(), FixedDec::new(0));
+        assert_eq!(FixedDec::<i32, 2>::new(1200).trunc(), FixedDec::new(1200));
+        assert_eq!(FixedDec::<i8, 1>::MIN.trunc(), FixedDec::new(-120));
+        assert_eq!(FixedDec::<u8, 2>::MAX.trunc(), FixedDec::new(200));
+        assert_eq!(FixedDec::<u8, 0>::MAX.trunc(), FixedDec::MAX);
+    }
+
+    #[test]
+    fn fract() {
+        let p = FixedDec::<i32, 2>::from_str("12.34").unwrap();
+        let n = FixedDec::<i32, 2>::from_str("-12.34").unwrap();
+        assert_eq!(p.fract(), FixedDec::from_str("0.34").unwrap());
+        assert_eq!(n.fract(), FixedDec::new(-34));
+        assert_eq!(p.trunc() + p.fract(), p);
+        assert_eq!(n.trunc() + n.fract(), n);
+
+        // integer values
+        assert_eq!(FixedDec::<i32, 2>::new(-1200).fract(), FixedDec::new(0));
+        assert_eq!(FixedDec::<u32, 2>::new(1200).fract(), FixedDec::new(0));
+        assert_eq!(FixedDec::<u32, 0>::new(7).fract(), FixedDec::new(0));
+
+        assert_eq!(FixedDec::<i8, 1>::MIN.fract(), FixedDec::new(-8));
+        assert_eq!(FixedDec::<u8, 2>::MAX.fract(), FixedDec::new(55));
+    }
+
+    #[test]
+    fn round_to_integral() {
+        use RoundingMode::*;
+
+        let x = FixedDec::<i32, 1>::from_str("2.5").unwrap();
+        assert_eq!(x.integral(), 2);
+        assert_eq!(x.round_to_integral(HalfEven), 2);
+        assert_eq!(x.round_to_integral(HalfUp), 3);
+        assert_eq!(x.round_to_integral(HalfDown), 2);
+        assert_eq!(x.round_to_integral(Floor), 2);
+        assert_eq!(x.round_to_integral(Ceiling), 3);
+
+        let x = FixedDec::<i32, 1>::from_str("-2.5").unwrap();
+        assert_eq!(x.integral(), -2);
+        assert_eq!(x.round_to_integral(HalfEven), -2);
+        assert_eq!(x.round_to_integral(HalfUp), -3);
+        assert_eq!(x.round_to_integral(HalfDown), -2);
+        assert_eq!(x.round_to_integral(Floor), -3);
+        assert_eq!(x.round_to_integral(Ceiling), -2);
+
+        let x = FixedDec::<i32, 1>::from_str("3.5").unwrap();
+        assert_eq!(x.round_to_integral(HalfEven), 4);
+        let x = FixedDec::<i32, 1>::from_str("-3.5").unwrap();
+        assert_eq!(x.round_to_integral(HalfEven), -4);
+        let x = FixedDec::<i32, 3>::from_str("-2.501").unwrap();
+        assert_eq!(x.round_to_integral(HalfEven), -3);
+
+        // carry at the bounds of the type
+        assert_eq!(FixedDec::<u8, 1>::MAX.round_to_integral(AwayFromZero), 26);
+        assert_eq!(FixedDec::<i8, 1>::MIN.round_to_integral(Floor), -13);
+        assert_eq!(FixedDec::<i8, 0>::MIN.round_to_integral(Floor), -128);
+        assert_eq!(FixedDec::<u128, 38>::MAX.round_to_integral(Ceiling), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "precision too big for the backing type")]
+    fn round_to_integral_invalid_precision() {
+        let x = FixedDec::<u16, 3>::new(123).try_into::<u8>().unwrap();
+        let _ = x.round_to_integral(RoundingMode::HalfUp);
+    }
+
+    #[test]
+    fn to_integer_rounded() {
+        use RoundingMode::*;
+
+        let x = FixedDec::<u32, 1>::from_str("12.5").unwrap();
+        assert_eq!(x.to_integer_rounded(HalfEven), Some(12));
+        assert_eq!(x.to_integer_rounded(HalfUp), Some(13));
+        assert_eq!(x.to_integer_rounded(TowardZero), Some(12));
+
+        let x = FixedDec::<i32, 2>::new(-1250);
+        assert_eq!(x.to_integer_rounded(HalfEven), Some(-12));
+        assert_eq!(x.to_integer_rounded(HalfUp), Some(-13));
+        assert_eq!(x.to_integer_rounded(Ceiling), Some(-12));
+        assert_eq!(x.to_integer_rounded(Floor), Some(-13));
+
+        // carry at the bounds of the type
+        assert_eq!(FixedDec::<u8, 1>::MAX.to_integer_rounded(HalfUp), Some(26));
+        assert_eq!(FixedDec::<i8, 1>::MIN.to_integer_rounded(HalfUp), Some(-13));
+        assert_eq!(
+            FixedDec::<u8, 0>::MAX.to_integer_rounded(Ceiling),
+            Some(255)
+        );
+
+        // 255.6 rounds to 256, which doesn't fit in u8 anymore
+        let x = FixedDec::<u16, 1>::from_str("255.6").unwrap();
+        assert_eq!(x.to_integer_rounded(HalfUp), Some(256));
+        assert!(u8::try_from(x.to_integer_rounded(HalfUp).unwrap()).is_err());
+        assert_eq!(x.to_integer_rounded(TowardZero), Some(255));
+
+        // precision too big for the backing type
+        let x = FixedDec::<u16, 3>::new(123).try_into::<u8>().unwrap();
+        assert_eq!(x.to_integer_rounded(HalfUp), None);
+    }
+
+    #[test]
+    fn si_units() {
+        type Milli = FixedDec<u64, 3>;
+        type Micro = FixedDec<u64, 6>;
+        type Nano = FixedDec<u64, 9>;
+
+        let m = Milli::new(1_234);
+        assert_eq!(m.to_micro(), Some(Micro::new(1_234_000)));
+        assert_eq!(m.to_nano(), Some(Nano::new(1_234_000_000)));
+        assert_eq!(m.to_micro().and_then(|u| u.to_milli()), Some(m));
+        assert_eq!(m.to_nano().and_then(|n| n.to_milli()), Some(m));
+        assert_eq!(m.to_milli(), Some(m));
+
+        // truncation when decreasing the precision
+        assert_eq!(Nano::new(1_999_999).to_milli(), Some(Milli::new(1)));
+        assert_eq!(Micro::new(1_999).to_milli(), Some(Milli::new(1)));
+
+        // overflow when increasing the precision
+        assert_eq!(Milli::MAX.to_nano(), None);
+        assert_eq!(FixedDec::<u32, 3>::new(5_000).to_nano(), None);
+        assert_eq!(
+            FixedDec::<u16, 0>::new(1).to_milli(),
+            Some(FixedDec::new(1000))
+        );
+        assert_eq!(FixedDec::<u16, 0>::new(1).to_micro(), None);
+    }
+
+    #[test]
+    fn add_scaled() {
+        let price = FixedDec::<u32, 2>::from_str("1.25").unwrap();
+        let fee = FixedDec::<u32, 4>::from_str("0.0075").unwrap();
+        let expected = FixedDec::<u32, 4>::from_str("1.2575").unwrap();
+
+        assert_eq!(price.add_scaled(fee), Some(expected));
+        assert_eq!(fee.add_scaled(price), Some(expected));
+        assert_eq!(
+            price.add_scaled::<4, 6>(fee),
+            Some(FixedDec::new(1_257_500))
+        );
+        assert_eq!(price.add_scaled::<4, 3>(fee), None);
+        assert_eq!(price.add_scaled::<2, 2>(price), Some(FixedDec::new(250)));
+
+        // the promotion of the operands overflow T, but not the result in T::Wide
+        let big = FixedDec::<i32, 0>::new(3_000_000);
+        let neg = FixedDec::<i32, 3>::new(-2_000_000_000);
+        assert_eq!(
+            big.add_scaled::<3, 3>(neg),
+            Some(FixedDec::new(1_000_000_000))
+        );
+        assert_eq!(big.add_scaled::<3, 3>(FixedDec::new(0)), None);
+    }
+
+    #[test]
+    fn fits_precision() {
+        let x1 = FixedDec::<u32, 3>::new(1200);
+        let x2 = FixedDec::<u32, 3>::new(1234);
+        let x3 = FixedDec::<u8, 1>::new(123);
+        let i1 = FixedDec::<i32, 3>::new(-1230);
+
+        assert!(x1.fits_precision::<1>());
+        assert!(!x1.fits_precision::<0>());
+        assert!(x1.fits_precision::<3>());
+        assert!(x1.fits_precision::<5>());
+        assert!(!x2.fits_precision::<1>());
+        assert!(x2.fits_precision::<3>());
+        assert!(i1.fits_precision::<2>());
+        assert!(!i1.fits_precision::<1>());
+
+        // overflow of the backing type
+        assert!(!x3.fits_precision::<2>());
+        assert!(x3.fits_precision::<1>());
+        assert!(FixedDec::<u8, 0>::new(2).fits_precision::<2>());
+    }
+
+    #[test]
+    fn widen_precision() {
+        let x1 = FixedDec::<u32, 2>::new(1234);
+        let x2 = FixedDec::<u8, 1>::new(123);
+
+        assert_eq!(x1.widen_precision::<2>(), Some(x1));
+        assert_eq!(x1.widen_precision::<4>(), Some(FixedDec::new(123400)));
+        assert_eq!(x1.widen_precision::<1>(), None);
+        assert_eq!(x1.widen_precision::<0>(), None);
+
+        // overflow of the backing type
+        assert_eq!(x2.widen_precision::<2>(), None);
+    }
+
+    #[test]
+    fn integral_digits() {
+        assert_eq!(FixedDec::<u32, 2>::new(123456).integral_digits(), 4);
+        assert_eq!(FixedDec::<u32, 2>::new(50).integral_digits(), 1);
+        assert_eq!(FixedDec::<u32, 2>::new(0).integral_digits(), 1);
+        assert_eq!(FixedDec::<u32, 2>::new(999).integral_digits(), 1);
+        assert_eq!(FixedDec::<u32, 2>::new(1000).integral_digits(), 2);
+        assert_eq!(FixedDec::<i32, 1>::new(-12345).integral_digits(), 4);
+        assert_eq!(FixedDec::<u8, 0>::MAX.integral_digits(), 3);
+        assert_eq!(FixedDec::<i8, 0>::MIN.integral_digits(), 3);
+        assert_eq!(FixedDec::<u128, 0>::MAX.integral_digits(), 39);
+
+        assert!(FixedDec::<u32, 2>::new(0).is_integer());
+        assert!(FixedDec::<u32, 2>::new(100).is_integer());
+        assert!(!FixedDec::<u32, 2>::new(150).is_integer());
+        assert!(FixedDec::<i32, 2>::new(-300).is_integer());
+        assert!(!FixedDec::<i32, 2>::new(-301).is_integer());
+        assert!(FixedDec::<u32, 0>::new(7).is_integer());
+    }
+
+    #[test]
+    fn parts() {
+        assert_eq!(
+            FixedDec::<u32, 2>::from_parts(12, 34),
+            Some(FixedDec::new(1234))
+        );
+        assert_eq!(
+            FixedDec::<u32, 2>::from_parts(0, 99),
+            Some(FixedDec::new(99))
+        );
+        assert_eq!(FixedDec::<u32, 2>::from_parts(12, 100), None);
+        assert_eq!(
+            FixedDec::<u32, 0>::from_parts(12, 0),
+            Some(FixedDec::new(12))
+        );
+        assert_eq!(FixedDec::<u32, 0>::from_parts(12, 1), None);
+        assert_eq!(
+            FixedDec::<u8, 2>::from_parts(2, 55),
+            Some(FixedDec::new(255))
+        );
+        assert_eq!(FixedDec::<u8, 2>::from_parts(2, 56), None);
+        assert_eq!(FixedDec::<u8, 2>::from_parts(3, 0), None);
+
+        assert_eq!(
+            FixedDec::<i32, 2>::from_parts(-12, -34),
+            Some(FixedDec::new(-1234))
+        );
+        assert_eq!(
+            FixedDec::<i32, 2>::from_parts(0, -34),
+            Some(FixedDec::new(-34))
+        );
+        assert_eq!(
+            FixedDec::<i32, 2>::from_parts(-12, 0),
+            Some(FixedDec::new(-1200))
+        );
+        assert_eq!(FixedDec::<i32, 2>::from_parts(-12, 34), None);
+        assert_eq!(FixedDec::<i32, 2>::from_parts(12, -34), None);
+        assert_eq!(FixedDec::<i32, 2>::from_parts(-12, -100), None);
+        assert_eq!(FixedDec::<i8, 2>::from_parts(-1, -28), Some(FixedDec::MIN));
+
+        for v in [
+            0,
+            1,
+            99,
+            100,
+            1234,
+            -1,
+            -99,
+            -100,
+            -1234,
+            i32::MAX,
+            i32::MIN,
+        ] {
+            let x = FixedDec::<i32, 2>::new(v);
+            let (i, f) = x.into_parts();
+            assert_eq!(FixedDec::from_parts(i, f), Some(x));
+        }
+        for v in [0, 1, 99, 100, 1234, u32::MAX] {
+            let x = FixedDec::<u32, 3>::new(v);
+            let (i, f) = x.into_parts();
+            assert_eq!(FixedDec::from_parts(i, f), Some(x));
+        }
+    }
+
+    #[test]
+    fn cmp_as() {
+        use core::cmp::Ordering;
+
+        let a = FixedDec::<u32, 2>::new(4_000_000_000);
+        let b = FixedDec::<u64, 2>::new(4_000_000_000);
+        assert_eq!(a.cmp_as(b), Some(Ordering::Equal));
+        assert_eq!(b.cmp_as(a), Some(Ordering::Equal));
+        assert_eq!(
+            a.cmp_as(FixedDec::<u64, 2>::new(5)),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(FixedDec::<u64, 2>::new(5).cmp_as(a), Some(Ordering::Less));
+
+        // the bigger value doesn't fit in the smaller type, but the smaller one does
+        let big = FixedDec::<u64, 2>::MAX;
+        assert_eq!(a.cmp_as(big), Some(Ordering::Less));
+        assert_eq!(big.cmp_as(a), Some(Ordering::Greater));
+
+        // mixed signedness
+        let n = FixedDec::<i64, 2>::new(-5);
+        assert_eq!(n.cmp_as(a), Some(Ordering::Less));
+        assert_eq!(a.cmp_as(n), Some(Ordering::Greater));
+        assert_eq!(n.cmp_as(FixedDec::<u64, 2>::MAX), None);
+    }
+
+    #[test]
+    fn cmp_scaled() {
+        use core::cmp::Ordering;
+
+        let x1 = FixedDec::<u32, 1>::new(15);
+        let x2 = FixedDec::<u32, 2>::new(150);
+        let x3 = FixedDec::<u32, 3>::new(1499);
+        let x4 = FixedDec::<i32, 0>::new(-2);
+        let x5 = FixedDec::<i32, 4>::new(-19999);
+
+        assert_eq!(x1.cmp_scaled(x2), Some(Ordering::Equal));
+        assert_eq!(x2.cmp_scaled(x1), Some(Ordering::Equal));
+        assert_eq!(x1.cmp_scaled(x3), Some(Ordering::Greater));
+        assert_eq!(x3.cmp_scaled(x2), Some(Ordering::Less));
+        assert_eq!(x4.cmp_scaled(x5), Some(Ordering::Less));
+        assert_eq!(x5.cmp_scaled(x4), Some(Ordering::Greater));
+
+        // scaling happens in the wider type
+        let m = FixedDec::<u64, 0>::MAX;
+        assert_eq!(
+            m.cmp_scaled(FixedDec::<u64, 19>::MAX),
+            Some(Ordering::Greater)
+        );
+
+        // no wider type for u128
+        let m = FixedDec::<u128, 0>::MAX;
+        assert_eq!(m.cmp_scaled(FixedDec::<u128, 1>::new(1)), None);
+        assert_eq!(
+            FixedDec::<u128, 0>::new(u128::MAX / 10).cmp_scaled(FixedDec::<u128, 1>::new(1)),
+            Some(Ordering::Greater)
+        );
+    }
+
+    #[test]
+    fn eq_value() {
+        let x1 = FixedDec::<u32, 1>::new(15);
+        assert!(x1.eq_value(x1));
+        assert!(x1.eq_value(FixedDec::<u32, 2>::new(150)));
+        assert!(x1.eq_value(FixedDec::<u32, 3>::new(1500)));
+        assert!(x1.eq_value(FixedDec::<u32, 9>::new(1_500_000_000)));
+        assert!(!x1.eq_value(FixedDec::<u32, 3>::new(1501)));
+        assert!(!x1.eq_value(FixedDec::<u32, 0>::new(1)));
+        assert!(FixedDec::<u32, 3>::new(2000).eq_value(FixedDec::<u32, 0>::new(2)));
+
+        let x2 = FixedDec::<i16, 0>::new(-3);
+        assert!(x2.eq_value(FixedDec::<i16, 4>::new(-30000)));
+        assert!(!x2.eq_value(FixedDec::<i16, 4>::new(30000)));
+
+        // scaling overflow can't be equal
+        assert!(!FixedDec::<u128, 0>::MAX.eq_value(FixedDec::<u128, 1>::MAX));
+    }
+
+    #[test]
+    fn range() {
+        use alloc::vec::Vec;
+
+        fn collect<T: Number, const P: u32>(start: T, end: T, step: T) -> Vec<T> {
+            FixedDec::<T, P>::range(
+                FixedDec::new(start),
+                FixedDec::new(end),
+                FixedDec::new(step),
+            )
+            .map(|x| x.value())
+            .collect()
+        }
+
+        assert_eq!(collect::<u32, 2>(0, 100, 25), [0, 25, 50, 75, 100]);
+        assert_eq!(collect::<u32, 2>(0, 90, 25), [0, 25, 50, 75]);
+        assert_eq!(collect::<u32, 2>(0, 0, 25), [0]);
+        assert_eq!(collect::<u32, 2>(10, 0, 25), []);
+        assert_eq!(collect::<u32, 2>(0, 100, 0), []);
+        assert_eq!(collect::<i32, 1>(10, -10, -5), [10, 5, 0, -5, -10]);
+        assert_eq!(collect::<i32, 1>(-10, 10, -5), []);
+
+        // stops on overflow
+        assert_eq!(collect::<u8, 1>(200, 255, 50), [200, 250]);
+        assert_eq!(collect::<i8, 1>(-100, -128, -20), [-100, -120]);
+    }
+
+    #[test]
+    fn const_accessors() {
+        const X1: FixedDec<u32, 3> = FixedDec::new(1234);
+        const X2: FixedDec<i64, 2> = FixedDec::new(-1234);
+        const I1: u32 = X1.integral_const();
+        const F1: u32 = X1.fractional_const();
+        const I2: i64 = X2.integral_const();
+        const F2: i64 = X2.fractional_const();
+        const PI_INT: u128 = crate::constants::PI128.integral_const();
+
+        assert_eq!((I1, F1), (X1.integral(), X1.fractional()));
+        assert_eq!((I2, F2), (X2.integral(), X2.fractional()));
+        assert_eq!(PI_INT, 3);
+
+        for v in [0, 1, -1, 999, -999, 1000, -1000, i16::MAX, i16::MIN] {
+            let x = FixedDec::<i16, 3>::new(v);
+            assert_eq!(x.integral_const(), x.integral());
+            assert_eq!(x.fractional_const(), x.fractional());
+        }
+    }
+
+    #[test]
+    fn from_ratio() {
+        use RoundingMode::*;
+
+        assert_eq!(
+            FixedDec::<u64, 4>::from_ratio(1, 3, HalfUp),
+            Some(FixedDec::new(3333))
+        );
+        assert_eq!(
+            FixedDec::<u64, 4>::from_ratio(2, 3, HalfUp),
+            Some(FixedDec::new(6667))
+        );
+        assert_eq!(
+            FixedDec::<u64, 4>::from_ratio(2, 3, TowardZero),
+            Some(FixedDec::new(6666))
+        );
+        assert_eq!(
+            FixedDec::<u64, 2>::from_ratio(1, 8, HalfEven),
+            Some(FixedDec::new(12))
+        );
+        assert_eq!(
+            FixedDec::<u64, 2>::from_ratio(1, 8, HalfUp),
+            Some(FixedDec::new(13))
+        );
+        assert_eq!(
+            FixedDec::<i32, 2>::from_ratio(-1, 8, HalfUp),
+            Some(FixedDec::new(-13))
+        );
+        assert_eq!(
+            FixedDec::<i32, 2>::from_ratio(1, -8, Floor),
+            Some(FixedDec::new(-13))
+        );
+        assert_eq!(
+            FixedDec::<u64, 0>::from_ratio(7, 2, HalfEven),
+            Some(FixedDec::new(4))
+        );
+
+        // num * 10^P overflow u8 but not the result
+        assert_eq!(
+            FixedDec::<u8, 2>::from_ratio(200, 100, HalfUp),
+            Some(FixedDec::new(200))
+        );
+        assert_eq!(FixedDec::<u8, 2>::from_ratio(3, 1, HalfUp), None);
+        assert_eq!(FixedDec::<u64, 4>::from_ratio(1, 0, HalfUp), None);
+    }
+
+    #[test]
+    fn mul_div() {
+        let x1 = FixedDec::<u32, 2>::new(4_000_000_000);
+        let x2 = FixedDec::<i64, 4>::new(-1_000_000);
+
+        assert_eq!(x1.checked_mul(3), None);
+        assert_eq!(x1.mul_div(3, 4), Some(FixedDec::new(3_000_000_000)));
+        assert_eq!(x1.mul_div(1, 3), Some(FixedDec::new(1_333_333_333)));
+        assert_eq!(x1.mul_div(5, 4), None);
+        assert_eq!(x1.mul_div(3, 0), None);
+
+        assert_eq!(x2.mul_div(i64::MAX, i64::MAX), Some(x2));
+        assert_eq!(x2.mul_div(8542, 10000), Some(FixedDec::new(-854_200)));
+        assert_eq!(x2.mul_div(1, -3), Some(FixedDec::new(333_333)));
+    }
+
+    #[test]
+    fn narrow_precision_with() {
+        let x1 = FixedDec::<u32, 3>::new(125);
+        let x2 = FixedDec::<i32, 3>::new(-125);
+        let x3 = FixedDec::<u8, 1>::new(255);
+
+        assert_eq!(
+            x1.narrow_precision_with::<2>(RoundingMode::HalfEven),
+            Some(FixedDec::new(12))
+        );
+        assert_eq!(
+            x1.narrow_precision_with::<2>(RoundingMode::HalfUp),
+            Some(FixedDec::new(13))
+        );
+        assert_eq!(
+            x1.narrow_precision_with::<2>(RoundingMode::TowardZero),
+            x1.set_precision::<2>()
+        );
+        assert_eq!(
+            x1.narrow_precision_with::<3>(RoundingMode::HalfUp),
+            Some(x1)
+        );
+        assert_eq!(x1.narrow_precision_with::<4>(RoundingMode::HalfUp), None);
+
+        assert_eq!(
+            x2.narrow_precision_with::<2>(RoundingMode::HalfEven),
+            Some(FixedDec::new(-12))
+        );
+        assert_eq!(
+            x2.narrow_precision_with::<2>(RoundingMode::HalfUp),
+            Some(FixedDec::new(-13))
+        );
+        assert_eq!(
+            x2.narrow_precision_with::<1>(RoundingMode::Floor),
+            Some(FixedDec::new(-2))
+        );
+
+        assert_eq!(
+            x3.narrow_precision_with::<0>(RoundingMode::HalfUp),
+            Some(FixedDec::new(26))
+        );
     }
 }
@@ -0,0 +1,90 @@
+use crate::FixedDec;
+use crate::number::{Number, ten_power};
+
+/// A FixedDec that is known to not be zero
+///
+/// Similar to the `core::num::NonZero*` types, this allows to validate a divisor once,
+/// and then divide by it without having to handle the division by zero case.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct NonZeroFixedDec<T: Number, const P: u32>(FixedDec<T, P>);
+
+impl<T: Number, const P: u32> NonZeroFixedDec<T, P> {
+    /// Create a NonZeroFixedDec if the given value is not zero
+    ///
+    /// ```
+    /// use fixeddec::{FixedDec, NonZeroFixedDec};
+    /// assert!(NonZeroFixedDec::new(FixedDec::<u32, 2>::new(150)).is_some());
+    /// assert!(NonZeroFixedDec::new(FixedDec::<u32, 2>::new(0)).is_none());
+    /// ```
+    pub fn new(value: FixedDec<T, P>) -> Option<Self> {
+        if value.0 == T::ZERO {
+            None
+        } else {
+            Some(Self(value))
+        }
+    }
+
+    /// Return the underlying FixedDec value
+    pub const fn get(self) -> FixedDec<T, P> {
+        self.0
+    }
+}
+
+impl<T: Number, const P: u32> From<NonZeroFixedDec<T, P>> for FixedDec<T, P> {
+    fn from(value: NonZeroFixedDec<T, P>) -> Self {
+        value.0
+    }
+}
+
+impl<T: Number, const P: u32> FixedDec<T, P> {
+    /// Division of two FixedDec where the divisor is known to be non zero
+    ///
+    /// The result is at precision P, and the digits after P are truncated. The computation
+    /// is done in the wider backing type `T::Wide`, and None is only returned if the
+    /// result doesn't fit in T.
+    ///
+    /// ```
+    /// use fixeddec::{FixedDec, NonZeroFixedDec};
+    /// let three = NonZeroFixedDec::new(FixedDec::<u32, 2>::new(300)).unwrap();
+    /// let x = FixedDec::<u32, 2>::new(1000);
+    /// assert_eq!(x.div_by_nonzero(three), Some(FixedDec::new(333)));
+    /// ```
+    pub fn div_by_nonzero(self, rhs: NonZeroFixedDec<T, P>) -> Option<Self> {
+        let prec = ten_power::<T::Wide>(P)?;
+        let q = self
+            .0
+            .to_wide()
+            .checked_mul(prec)?
+            .checked_div(rhs.0.0.to_wide())?;
+        T::try_from_wide(q).map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nonzero() {
+        assert_eq!(NonZeroFixedDec::new(FixedDec::<u32, 2>::new(0)), None);
+        assert_eq!(NonZeroFixedDec::new(FixedDec::<i32, 2>::new(0)), None);
+        assert_eq!(NonZeroFixedDec::new(FixedDec::<u128, 0>::new(0)), None);
+
+        let x = FixedDec::<i32, 2>::new(-1);
+        assert_eq!(NonZeroFixedDec::new(x).map(|nz| nz.get()), Some(x));
+
+        let half = NonZeroFixedDec::new(FixedDec::<u8, 1>::new(5)).unwrap();
+        assert_eq!(
+            FixedDec::<u8, 1>::new(10).div_by_nonzero(half),
+            Some(FixedDec::new(20))
+        );
+        assert_eq!(FixedDec::<u8, 1>::new(200).div_by_nonzero(half), None);
+
+        let neg_four = NonZeroFixedDec::new(FixedDec::<i64, 3>::new(-4000)).unwrap();
+        assert_eq!(
+            FixedDec::<i64, 3>::new(1000).div_by_nonzero(neg_four),
+            Some(FixedDec::new(-250))
+        );
+    }
+}
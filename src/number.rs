@@ -1,7 +1,28 @@
-use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
+/// Integer types usable as the backing value of a FixedDec
+///
+/// This trait is implemented for all the rust builtin integer types, and can be implemented
+/// for other integer types (e.g. a 256 bits integer) to go beyond the 38 digits of `u128`.
+///
+/// The implementation need to behave like a builtin integer:
+///
+/// * the operators follow the integer semantics, with the division truncating toward zero,
+///   and are allowed to panic on overflow or division by zero
+/// * `MIN` is `ZERO` for unsigned types, and negative for signed types
+/// * `TEN_POWER` contains all the powers of ten representable by the type, starting at
+///   `10^0 = ONE`. Its length defines the maximum precision `P` of a FixedDec of this type
+/// * the `Display` instance writes the decimal representation of the value (with a leading
+///   `-` when negative), honoring the width and zero-padding flags like `Formatter::pad_integral`
+/// * `Wide` is an integer type whose range includes the range of the type, and is used
+///   to compute intermediate results. A type without wider type can be its own `Wide`
+/// * `Signed` is the signed integer type of the same size, used to represent differences
+///   of unsigned values. A signed type, or a type without signed counterpart, can be its
+///   own `Signed`
 pub trait Number:
     Copy
+    + Eq
+    + Ord
     + Add<Output = Self>
     + AddAssign
     + Sub<Output = Self>
@@ -14,21 +35,109 @@ pub trait Number:
     + alloc::fmt::Display
     + 'static
 {
+    /// All the powers of ten representable by the type, indexed by the exponent
+    ///
+    /// The table is usable in const context, for example to compute constants:
+    ///
+    /// ```
+    /// use fixeddec::Number;
+    /// const MILLION: u64 = <u64 as Number>::TEN_POWER[6];
+    /// const DIGITS: usize = <u64 as Number>::TEN_POWER.len();
+    /// assert_eq!(MILLION, 1_000_000);
+    /// assert_eq!(DIGITS, 20);
+    /// ```
     const TEN_POWER: &'static [Self];
+    /// The smallest value of the type
     const MIN: Self;
+    /// The largest value of the type
     const MAX: Self;
+    /// The value zero
     const ZERO: Self;
+    /// The value one
+    const ONE: Self;
 
+    /// The wider integer type used to compute intermediate results without overflow
+    ///
+    /// Each builtin type is widened to the type of double its size (e.g. `u64::Wide` is `u128`),
+    /// and the widest builtin types (u128 and i128) are their own wide type, so computation
+    /// in the wide domain for those saturate at the same range as the original type.
+    type Wide: Number;
+
+    /// The signed integer type of the same size, used for results that can be negative
+    ///
+    /// Each unsigned builtin type maps to the signed type of the same size (e.g. `u32::Signed`
+    /// is `i32`), and the signed builtin types are their own signed type.
+    type Signed: Number;
+
+    /// Addition, None on overflow
     fn checked_add(self, rhs: Self) -> Option<Self>;
+    /// Subtraction, None on overflow
     fn checked_sub(self, rhs: Self) -> Option<Self>;
+    /// Multiplication, None on overflow
     fn checked_mul(self, rhs: Self) -> Option<Self>;
+    /// Division truncated toward zero, None on overflow or if rhs is zero
     fn checked_div(self, rhs: Self) -> Option<Self>;
+    /// Remainder of the division truncated toward zero, with the sign of self,
+    /// None on overflow or if rhs is zero
     fn checked_rem(self, rhs: Self) -> Option<Self>;
 
+    /// Absolute difference between self and other, None if it doesn't fit in the type
+    fn abs_diff(self, other: Self) -> Option<Self>;
+
+    /// Midpoint between self and other, rounded toward zero, without overflowing
+    fn midpoint(self, other: Self) -> Self;
+
+    /// Convert a decimal digit character to its value, None if not a digit
     fn from_digit10(c: char) -> Option<Self>;
+
+    /// Convert to the nearest f64
+    fn to_f64(self) -> f64;
+
+    /// Convert from a f64 truncated toward zero, None if not finite or out of the type range
+    fn from_f64_trunc(v: f64) -> Option<Self>;
+
+    /// Convert into the wide type, which is always lossless
+    fn to_wide(self) -> Self::Wide;
+
+    /// Convert back from the wide type, returning None if the value doesn't fit
+    fn try_from_wide(wide: Self::Wide) -> Option<Self>;
+
+    /// Convert into the signed type, returning None if the value doesn't fit
+    fn try_to_signed(self) -> Option<Self::Signed>;
+}
+
+/// Signed integer types usable as the backing value of a FixedDec
+///
+/// This trait is implemented for the signed builtin integer types, and gives access to the
+/// negation, which is not defined for unsigned types. As `MIN` has no positive counterpart,
+/// the negation of `MIN` overflows.
+pub trait SignedNumber: Number + Neg<Output = Self> {
+    /// Negation, None on overflow (negation of `MIN`)
+    fn checked_neg(self) -> Option<Self>;
+
+    /// Negation wrapping around at the bounds of the type, so `MIN` is its own negation
+    fn wrapping_neg(self) -> Self;
 }
 
-pub(crate) const fn ten_power<T: Number>(p: u32) -> Option<T> {
+/// Return 10 to the power of `p` in the type T
+///
+/// The powers of ten are not computed but read from a table of all the powers
+/// representable by T, so if `10^p` overflows T, None is returned.
+///
+/// ```
+/// use fixeddec::ten_power;
+/// assert_eq!(ten_power::<u32>(3), Some(1000));
+/// assert_eq!(ten_power::<u8>(3), None);
+/// ```
+///
+/// This is usable in const context:
+///
+/// ```
+/// use fixeddec::ten_power;
+/// const BILLION: u64 = ten_power::<u64>(9).unwrap();
+/// assert_eq!(BILLION, 1_000_000_000);
+/// ```
+pub const fn ten_power<T: Number>(p: u32) -> Option<T> {
     if T::TEN_POWER.len() > p as usize {
         Some(T::TEN_POWER[p as usize])
     } else {
@@ -36,13 +145,33 @@ pub(crate) const fn ten_power<T: Number>(p: u32) -> Option<T> {
     }
 }
 
+/// Greatest common divisor of a and b, up to the sign
+///
+/// The result is zero only if both a and b are zero.
+pub(crate) fn gcd<T: Number>(mut a: T, mut b: T) -> T {
+    while b != T::ZERO {
+        // the only failing case is MIN % -1, whose remainder is zero
+        let r = a.checked_rem(b).unwrap_or(T::ZERO);
+        a = b;
+        b = r;
+    }
+    a
+}
+
+/// Return 2 to the power of `n` as f64, which is exact for all the integer sizes
+const fn pow2(n: u32) -> f64 {
+    f64::from_bits(((1023 + n) as u64) << 52)
+}
+
 macro_rules! number_impl {
-    ($ty:ty, $power10:expr, $($tt:tt)+) => {
+    ($ty:ty, $wide:ty, $power10:expr, $($tt:tt)+) => {
         impl Number for $ty {
             const MIN : $ty = <$ty>::MIN;
             const MAX : $ty = <$ty>::MAX;
             const ZERO : $ty = 0;
+            const ONE : $ty = 1;
             const TEN_POWER : &'static [$ty] = &$power10;
+            type Wide = $wide;
             fn checked_add(self, rhs: $ty) -> Option<$ty> {
                 self.checked_add(rhs)
             }
@@ -55,10 +184,22 @@ macro_rules! number_impl {
             fn checked_div(self, rhs: $ty) -> Option<$ty> {
                 self.checked_div(rhs)
             }
+            fn checked_rem(self, rhs: $ty) -> Option<$ty> {
+                self.checked_rem(rhs)
+            }
             fn from_digit10(c: char) -> Option<$ty> {
                 // all rust integral type can represent number between 0-9
                 c.to_digit(10).map(|i| i as $ty)
             }
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+            fn to_wide(self) -> $wide {
+                <$wide>::from(self)
+            }
+            fn try_from_wide(wide: $wide) -> Option<$ty> {
+                <$ty>::try_from(wide).ok()
+            }
 
             $($tt)+
         }
@@ -66,32 +207,71 @@ macro_rules! number_impl {
 }
 
 macro_rules! number_unsigned_impl {
-    ($ty:ty,$power10:expr) => {
-        number_impl!(
+    ($ty:ty,$wide:ty,$signed:ty,$power10:expr) => {
+        number_impl! {
             $ty,
+            $wide,
             $power10,
-            fn checked_rem(self, rhs: $ty) -> Option<$ty> {
-                self.checked_rem(rhs)
+            type Signed = $signed;
+            fn try_to_signed(self) -> Option<$signed> {
+                <$signed>::try_from(self).ok()
+            }
+            fn abs_diff(self, other: $ty) -> Option<$ty> {
+                Some(self.abs_diff(other))
+            }
+            fn midpoint(self, other: $ty) -> $ty {
+                (self & other) + ((self ^ other) >> 1)
+            }
+            fn from_f64_trunc(v: f64) -> Option<$ty> {
+                // NaN compares false, and infinites are out of range
+                (v > -1.0 && v < pow2(<$ty>::BITS)).then(|| v as $ty)
             }
-        );
+        }
     };
 }
 macro_rules! number_signed_impl {
-    ($ty:ty,$power10:expr) => {
-        number_impl!(
+    ($ty:ty,$wide:ty,$power10:expr) => {
+        number_impl! {
             $ty,
+            $wide,
             $power10,
-            fn checked_rem(self, rhs: $ty) -> Option<$ty> {
-                self.abs().checked_rem(rhs)
+            type Signed = $ty;
+            fn try_to_signed(self) -> Option<$ty> {
+                Some(self)
+            }
+            fn abs_diff(self, other: $ty) -> Option<$ty> {
+                <$ty>::try_from(self.abs_diff(other)).ok()
             }
-        );
+            fn midpoint(self, other: $ty) -> $ty {
+                // floor of the midpoint, then adjusted for rounding toward zero
+                let floor = (self & other) + ((self ^ other) >> 1);
+                if floor < 0 { floor + ((self ^ other) & 1) } else { floor }
+            }
+            fn from_f64_trunc(v: f64) -> Option<$ty> {
+                // NaN compares false, and infinites are out of range. MIN - 1 is not representable
+                // as f64 for the bigger types, so MIN itself need to be checked separately
+                let min = -pow2(<$ty>::BITS - 1);
+                ((v > min - 1.0 || v == min) && v < -min).then(|| v as $ty)
+            }
+        }
+
+        impl SignedNumber for $ty {
+            fn checked_neg(self) -> Option<$ty> {
+                self.checked_neg()
+            }
+            fn wrapping_neg(self) -> $ty {
+                self.wrapping_neg()
+            }
+        }
     };
 }
 
-number_unsigned_impl!(u8, [1, 10, 100]);
-number_unsigned_impl!(u16, [1, 10, 100, 1000, 10000]);
+number_unsigned_impl!(u8, u16, i8, [1, 10, 100]);
+number_unsigned_impl!(u16, u32, i16, [1, 10, 100, 1000, 10000]);
 number_unsigned_impl!(
     u32,
+    u64,
+    i32,
     [
         1,
         10,
@@ -107,6 +287,8 @@ number_unsigned_impl!(
 );
 number_unsigned_impl!(
     u64,
+    u128,
+    i64,
     [
         1,
         10,
@@ -132,6 +314,8 @@ number_unsigned_impl!(
 );
 number_unsigned_impl!(
     u128,
+    u128,
+    i128,
     [
         1,
         10,
@@ -175,10 +359,11 @@ number_unsigned_impl!(
     ]
 );
 
-number_signed_impl!(i8, [1, 10, 100]);
-number_signed_impl!(i16, [1, 10, 100, 1000, 10000]);
+number_signed_impl!(i8, i16, [1, 10, 100]);
+number_signed_impl!(i16, i32, [1, 10, 100, 1000, 10000]);
 number_signed_impl!(
     i32,
+    i64,
     [
         1,
         10,
@@ -194,6 +379,7 @@ number_signed_impl!(
 );
 number_signed_impl!(
     i64,
+    i128,
     [
         1,
         10,
@@ -217,6 +403,7 @@ number_signed_impl!(
     ]
 );
 number_signed_impl!(
+    i128,
     i128,
     [
         1,
@@ -260,3 +447,391 @@ number_signed_impl!(
         100_000_000_000_000_000_000_000_000_000_000_000_000,
     ]
 );
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roundtrip<T: Number>(values: &[T]) {
+        for v in values {
+            assert_eq!(T::try_from_wide(v.to_wide()), Some(*v));
+        }
+    }
+
+    #[test]
+    fn wide() {
+        roundtrip(&[0u8, 1, u8::MAX]);
+        roundtrip(&[0u16, 1, u16::MAX]);
+        roundtrip(&[0u32, 1, u32::MAX]);
+        roundtrip(&[0u64, 1, u64::MAX]);
+        roundtrip(&[0u128, 1, u128::MAX]);
+        roundtrip(&[0i8, 1, -1, i8::MIN, i8::MAX]);
+        roundtrip(&[0i16, 1, -1, i16::MIN, i16::MAX]);
+        roundtrip(&[0i32, 1, -1, i32::MIN, i32::MAX]);
+        roundtrip(&[0i64, 1, -1, i64::MIN, i64::MAX]);
+        roundtrip(&[0i128, 1, -1, i128::MIN, i128::MAX]);
+
+        assert_eq!(u8::MAX.to_wide(), 255u16);
+        assert_eq!(i64::MIN.to_wide(), i64::MIN as i128);
+        assert_eq!(u8::try_from_wide(256), None);
+        assert_eq!(i8::try_from_wide(-129), None);
+        assert_eq!(u64::try_from_wide(u64::MAX as u128 + 1), None);
+        assert_eq!(i32::try_from_wide(i32::MIN as i64 - 1), None);
+    }
+
+    #[test]
+    fn gcd() {
+        use super::gcd;
+        assert_eq!(gcd(150u32, 100), 50);
+        assert_eq!(gcd(100u32, 150), 50);
+        assert_eq!(gcd(7u8, 0), 7);
+        assert_eq!(gcd(0u8, 7), 7);
+        assert_eq!(gcd(0u8, 0), 0);
+        assert_eq!(gcd(17u64, 10), 1);
+        assert_eq!(gcd(-150i32, 100).abs(), 50);
+        assert_eq!(gcd(100i32, -150).abs(), 50);
+        assert_eq!(gcd(i64::MIN, -1).abs(), 1);
+        assert_eq!(gcd(i64::MIN, 1 << 40).abs(), 1 << 40);
+        assert_eq!(gcd(u128::MAX, u128::MAX), u128::MAX);
+    }
+
+    #[test]
+    fn f64_trunc() {
+        assert_eq!(u8::from_f64_trunc(255.9), Some(255));
+        assert_eq!(u8::from_f64_trunc(256.0), None);
+        assert_eq!(u8::from_f64_trunc(-0.9), Some(0));
+        assert_eq!(u8::from_f64_trunc(-1.0), None);
+        assert_eq!(i8::from_f64_trunc(-128.9), Some(-128));
+        assert_eq!(i8::from_f64_trunc(-129.0), None);
+        assert_eq!(i8::from_f64_trunc(127.9), Some(127));
+        assert_eq!(i8::from_f64_trunc(128.0), None);
+        assert_eq!(i64::from_f64_trunc(i64::MIN as f64), Some(i64::MIN));
+        assert_eq!(i64::from_f64_trunc(-(i64::MIN as f64)), None);
+        assert_eq!(u64::from_f64_trunc(u64::MAX as f64), None);
+        assert_eq!(u128::from_f64_trunc(1e38), Some(1e38 as u128));
+        assert_eq!(u32::from_f64_trunc(f64::NAN), None);
+        assert_eq!(u32::from_f64_trunc(f64::INFINITY), None);
+        assert_eq!(i32::from_f64_trunc(f64::NEG_INFINITY), None);
+    }
+
+    #[test]
+    fn abs_diff() {
+        assert_eq!(Number::abs_diff(3u8, 10), Some(7));
+        assert_eq!(Number::abs_diff(10u8, 3), Some(7));
+        assert_eq!(Number::abs_diff(0u8, u8::MAX), Some(u8::MAX));
+        assert_eq!(Number::abs_diff(-3i8, 10), Some(13));
+        assert_eq!(Number::abs_diff(10i8, -3), Some(13));
+        assert_eq!(Number::abs_diff(0i8, i8::MAX), Some(i8::MAX));
+        assert_eq!(Number::abs_diff(-1i8, i8::MAX), None);
+        assert_eq!(Number::abs_diff(i8::MIN, 0), None);
+        assert_eq!(Number::abs_diff(i8::MIN, i8::MAX), None);
+    }
+
+    #[test]
+    fn ten_power_const() {
+        const TABLE: &[u32] = <u32 as Number>::TEN_POWER;
+        const LAST: i128 = <i128 as Number>::TEN_POWER[38];
+        const NONE: Option<u8> = ten_power::<u8>(3);
+        const SOME: Option<i16> = ten_power::<i16>(4);
+
+        assert_eq!(TABLE.len(), 10);
+        for (i, p) in TABLE.iter().enumerate() {
+            assert_eq!(*p, 10u32.pow(i as u32));
+        }
+        assert_eq!(LAST, 10i128.pow(38));
+        assert_eq!(NONE, None);
+        assert_eq!(SOME, Some(10_000));
+    }
+
+    #[test]
+    fn midpoint() {
+        assert_eq!(Number::midpoint(3u8, 10), 6);
+        assert_eq!(Number::midpoint(u8::MAX, u8::MAX - 2), u8::MAX - 1);
+        assert_eq!(Number::midpoint(u8::MAX, u8::MAX - 1), u8::MAX - 1);
+        assert_eq!(Number::midpoint(0u128, u128::MAX), u128::MAX / 2);
+        assert_eq!(Number::midpoint(-3i8, 10), 3);
+        assert_eq!(Number::midpoint(3i8, -10), -3);
+        assert_eq!(Number::midpoint(i8::MAX, i8::MAX - 1), i8::MAX - 1);
+        assert_eq!(Number::midpoint(i8::MIN, i8::MIN + 1), i8::MIN + 1);
+        assert_eq!(Number::midpoint(i8::MIN, i8::MAX), 0);
+        assert_eq!(Number::midpoint(i128::MIN, i128::MIN), i128::MIN);
+    }
+
+    /// Minimal 256 bits unsigned integer, to check that Number is implementable for a
+    /// type defined outside of this crate
+    #[allow(clippy::needless_range_loop)]
+    mod u256 {
+        use super::super::Number;
+        use crate::FixedDec;
+        use alloc::format;
+        use alloc::string::ToString;
+        use core::cmp::Ordering;
+        use core::fmt;
+        use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+        const TWO64: f64 = 18446744073709551616.0;
+
+        /// little endian 64 bits limbs
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        struct U256([u64; 4]);
+
+        const fn mul10(a: U256) -> U256 {
+            let mut out = [0u64; 4];
+            let mut carry = 0u128;
+            let mut i = 0;
+            while i < 4 {
+                let v = a.0[i] as u128 * 10 + carry;
+                out[i] = v as u64;
+                carry = v >> 64;
+                i += 1;
+            }
+            U256(out)
+        }
+
+        const fn powers() -> [U256; 78] {
+            let mut table = [U256([0; 4]); 78];
+            table[0] = U256([1, 0, 0, 0]);
+            let mut i = 1;
+            while i < 78 {
+                table[i] = mul10(table[i - 1]);
+                i += 1;
+            }
+            table
+        }
+
+        impl U256 {
+            fn bit(&self, i: usize) -> bool {
+                (self.0[i / 64] >> (i % 64)) & 1 == 1
+            }
+
+            fn shl1(self) -> (Self, bool) {
+                let mut out = [0u64; 4];
+                for i in 0..4 {
+                    out[i] = (self.0[i] << 1) | if i > 0 { self.0[i - 1] >> 63 } else { 0 };
+                }
+                (U256(out), self.0[3] >> 63 == 1)
+            }
+
+            fn shr1(self) -> Self {
+                let mut out = [0u64; 4];
+                for i in 0..4 {
+                    out[i] = (self.0[i] >> 1) | if i < 3 { self.0[i + 1] << 63 } else { 0 };
+                }
+                U256(out)
+            }
+
+            fn div_rem(self, rhs: Self) -> Option<(Self, Self)> {
+                if rhs == Self::ZERO {
+                    return None;
+                }
+                let mut q = Self::ZERO;
+                let mut r = Self::ZERO;
+                for i in (0..256).rev() {
+                    let (shifted, overflow) = r.shl1();
+                    r = shifted;
+                    r.0[0] |= self.bit(i) as u64;
+                    if overflow || r >= rhs {
+                        r = r.wrapping_sub(rhs);
+                        q.0[i / 64] |= 1 << (i % 64);
+                    }
+                }
+                Some((q, r))
+            }
+
+            fn wrapping_sub(self, rhs: Self) -> Self {
+                let mut out = [0u64; 4];
+                let mut borrow = false;
+                for i in 0..4 {
+                    let (v, b1) = self.0[i].overflowing_sub(rhs.0[i]);
+                    let (v, b2) = v.overflowing_sub(borrow as u64);
+                    out[i] = v;
+                    borrow = b1 || b2;
+                }
+                U256(out)
+            }
+        }
+
+        impl Ord for U256 {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.iter().rev().cmp(other.0.iter().rev())
+            }
+        }
+
+        impl PartialOrd for U256 {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl fmt::Display for U256 {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let mut digits = [0u8; 78];
+                let mut start = digits.len();
+                let mut v = *self;
+                loop {
+                    let (q, r) = v.div_rem(Self::TEN_POWER[1]).unwrap();
+                    start -= 1;
+                    digits[start] = b'0' + r.0[0] as u8;
+                    v = q;
+                    if v == Self::ZERO {
+                        break;
+                    }
+                }
+                f.pad_integral(true, "", core::str::from_utf8(&digits[start..]).unwrap())
+            }
+        }
+
+        macro_rules! op {
+            ($imp:ident, $method:ident, $assign:ident, $assign_method:ident, $checked:ident) => {
+                impl $imp for U256 {
+                    type Output = U256;
+                    fn $method(self, rhs: U256) -> U256 {
+                        Number::$checked(self, rhs).expect("U256 overflow")
+                    }
+                }
+                impl $assign for U256 {
+                    fn $assign_method(&mut self, rhs: U256) {
+                        *self = $imp::$method(*self, rhs)
+                    }
+                }
+            };
+        }
+        op!(Add, add, AddAssign, add_assign, checked_add);
+        op!(Sub, sub, SubAssign, sub_assign, checked_sub);
+        op!(Mul, mul, MulAssign, mul_assign, checked_mul);
+        op!(Div, div, DivAssign, div_assign, checked_div);
+
+        impl Number for U256 {
+            const TEN_POWER: &'static [Self] = &powers();
+            const MIN: Self = U256([0; 4]);
+            const MAX: Self = U256([u64::MAX; 4]);
+            const ZERO: Self = U256([0; 4]);
+            const ONE: Self = U256([1, 0, 0, 0]);
+            type Wide = U256;
+            type Signed = U256;
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                let mut out = [0u64; 4];
+                let mut carry = false;
+                for i in 0..4 {
+                    let (v, c1) = self.0[i].overflowing_add(rhs.0[i]);
+                    let (v, c2) = v.overflowing_add(carry as u64);
+                    out[i] = v;
+                    carry = c1 || c2;
+                }
+                (!carry).then_some(U256(out))
+            }
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                (self >= rhs).then(|| self.wrapping_sub(rhs))
+            }
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                let mut out = [0u64; 8];
+                for i in 0..4 {
+                    let mut carry = 0u128;
+                    for j in 0..4 {
+                        let v = self.0[i] as u128 * rhs.0[j] as u128 + out[i + j] as u128 + carry;
+                        out[i + j] = v as u64;
+                        carry = v >> 64;
+                    }
+                    out[i + 4] = carry as u64;
+                }
+                out[4..]
+                    .iter()
+                    .all(|l| *l == 0)
+                    .then(|| U256([out[0], out[1], out[2], out[3]]))
+            }
+            fn checked_div(self, rhs: Self) -> Option<Self> {
+                self.div_rem(rhs).map(|(q, _)| q)
+            }
+            fn checked_rem(self, rhs: Self) -> Option<Self> {
+                self.div_rem(rhs).map(|(_, r)| r)
+            }
+            fn abs_diff(self, other: Self) -> Option<Self> {
+                Some(self.max(other).wrapping_sub(self.min(other)))
+            }
+            fn midpoint(self, other: Self) -> Self {
+                let (lo, hi) = (self.min(other), self.max(other));
+                lo + hi.wrapping_sub(lo).shr1()
+            }
+            fn from_digit10(c: char) -> Option<Self> {
+                c.to_digit(10).map(|d| U256([d as u64, 0, 0, 0]))
+            }
+            fn to_f64(self) -> f64 {
+                self.0
+                    .iter()
+                    .rev()
+                    .fold(0.0, |acc, l| acc * TWO64 + *l as f64)
+            }
+            fn from_f64_trunc(v: f64) -> Option<Self> {
+                if !(v > -1.0 && v < TWO64 * TWO64 * TWO64 * TWO64) {
+                    return None;
+                }
+                let mut rest = v.trunc();
+                let mut out = [0u64; 4];
+                for i in (0..4).rev() {
+                    let unit = (0..i).fold(1.0, |acc, _| acc * TWO64);
+                    let limb = (rest / unit).trunc();
+                    out[i] = limb as u64;
+                    rest -= limb * unit;
+                }
+                Some(U256(out))
+            }
+            fn to_wide(self) -> Self {
+                self
+            }
+            fn try_from_wide(wide: Self) -> Option<Self> {
+                Some(wide)
+            }
+            fn try_to_signed(self) -> Option<Self> {
+                Some(self)
+            }
+        }
+
+        #[test]
+        fn fixeddec_u256() {
+            type F = FixedDec<U256, 40>;
+            let digits = "12345678901234567890.1234567890123456789012345678901234567890";
+            let x = F::from_str(digits).unwrap();
+            assert_eq!(x.to_string(), digits);
+            assert_eq!(x.integral().to_string(), "12345678901234567890");
+            assert_eq!(F::max_integral().to_string().len(), 38);
+
+            let sum = x.checked_add(x).unwrap();
+            assert_eq!(
+                sum.to_string(),
+                "24691357802469135780.2469135780246913578024691357802469135780"
+            );
+            assert_eq!(sum.checked_sub(x), Some(x));
+            assert!(F::MAX.checked_add(x).is_none());
+
+            type G = FixedDec<U256, 30>;
+            let a = G::from_str("1.5").unwrap();
+            let b = G::from_str("-2.5");
+            assert!(b.is_none());
+            let b = G::from_str("2.5").unwrap();
+            assert_eq!(
+                a.checked_mul_fixed(b).unwrap().to_string(),
+                "3.750000000000000000000000000000"
+            );
+            assert_eq!(format!("{:.2}", a), "1.50");
+            assert_eq!(format!("{:08.1}", a), "000001.5");
+            assert_eq!(G::from_f64(2.5), Some(b));
+        }
+
+        #[test]
+        fn fixeddec_u256_max() {
+            // more digits than the inline formatting buffer
+            type F = FixedDec<U256, 40>;
+            let max =
+                "11579208923731619542357098500868790785.3269984665640564039457584007913129639935";
+            assert_eq!(F::MAX.to_string(), max);
+            assert_eq!(F::from_str(max), Some(F::MAX));
+            assert_eq!(
+                format!("{:.1}", F::MAX),
+                "11579208923731619542357098500868790785.3"
+            );
+            assert_eq!(format!("{:>80}", F::MAX), format!(" {max}"));
+            assert_eq!(F::MAX.to_canonical_string(), max);
+        }
+    }
+}
@@ -0,0 +1,294 @@
+use alloc::string::String;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::FixedDec;
+use crate::number::{Number, ten_power};
+
+/// Error returned when parsing a FixedDec from a string fails
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseFixedDecError {
+    /// The string doesn't contain any digit
+    Empty,
+    /// The string contains a character which is not part of a decimal number
+    InvalidDigit {
+        /// The byte offset of the first invalid character in the string
+        position: usize,
+    },
+    /// The number is outside the range of the type
+    Overflow,
+}
+
+impl fmt::Display for ParseFixedDecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("cannot parse decimal from empty string"),
+            Self::InvalidDigit { position } => {
+                write!(f, "invalid digit found in decimal at position {position}")
+            }
+            Self::Overflow => f.write_str("decimal out of range of the type"),
+        }
+    }
+}
+
+impl core::error::Error for ParseFixedDecError {}
+
+/// Parse a decimal number (e.g. "-1.234") into a FixedDec
+///
+/// A leading `-` is only accepted for signed types, and the digits after the precision
+/// P are checked but truncated.
+pub(crate) fn parse<T: Number, const P: u32>(
+    s: &str,
+) -> Result<FixedDec<T, P>, ParseFixedDecError> {
+    parse_with_separator(s, '.')
+}
+
+/// Parse a decimal number using `sep` as the decimal separator
+///
+/// The separator can't be a digit or `-`, as the number would be ambiguous.
+pub(crate) fn parse_with_separator<T: Number, const P: u32>(
+    s: &str,
+    sep: char,
+) -> Result<FixedDec<T, P>, ParseFixedDecError> {
+    if sep.is_ascii_digit() || sep == '-' {
+        return Err(ParseFixedDecError::InvalidDigit { position: 0 });
+    }
+    let (negative, digits) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s),
+    };
+    if negative && T::MIN == T::ZERO {
+        return Err(ParseFixedDecError::InvalidDigit { position: 0 });
+    }
+    let offset = usize::from(negative);
+    let ten = ten_power::<T>(1).unwrap(); // safe all types have 10
+
+    let mut acc = T::ZERO;
+    let mut has_digits = false;
+    let mut frac_digits: Option<u32> = None;
+    for (i, c) in digits.char_indices() {
+        if c == sep && frac_digits.is_none() {
+            frac_digits = Some(0);
+            continue;
+        }
+        let d = T::from_digit10(c).ok_or(ParseFixedDecError::InvalidDigit {
+            position: offset + i,
+        })?;
+        has_digits = true;
+        match frac_digits {
+            Some(f) if f >= P => continue,
+            Some(f) => frac_digits = Some(f + 1),
+            None => {}
+        }
+        // accumulating negative numbers as negative allows to parse MIN
+        let next = acc.checked_mul(ten).and_then(|v| {
+            if negative {
+                v.checked_sub(d)
+            } else {
+                v.checked_add(d)
+            }
+        });
+        acc = next.ok_or(ParseFixedDecError::Overflow)?;
+    }
+    if !has_digits {
+        return Err(ParseFixedDecError::Empty);
+    }
+
+    ten_power::<T>(P - frac_digits.unwrap_or(0))
+        .and_then(|scale| acc.checked_mul(scale))
+        .map(FixedDec::new)
+        .ok_or(ParseFixedDecError::Overflow)
+}
+
+/// Parse a decimal number (e.g. "-1.2300"), returning its raw value and its precision
+///
+/// The precision is the number of fractional digits written in the string, including
+/// the trailing zeros, so the value can be converted to any FixedDec or DynFixedDec.
+///
+/// ```
+/// use fixeddec::parse_decimal;
+/// assert_eq!(parse_decimal("1.2300"), Some((12300, 4)));
+/// assert_eq!(parse_decimal("-42"), Some((-42, 0)));
+/// assert_eq!(parse_decimal("4x"), None);
+/// ```
+///
+/// Returns None if the string is not a decimal number, or if its raw value doesn't fit in i128
+pub fn parse_decimal(s: &str) -> Option<(i128, u32)> {
+    let (negative, digits) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s),
+    };
+    let mut acc: i128 = 0;
+    let mut has_digits = false;
+    let mut frac_digits: Option<u32> = None;
+    for c in digits.chars() {
+        if c == '.' && frac_digits.is_none() {
+            frac_digits = Some(0);
+            continue;
+        }
+        let d = i128::from(c.to_digit(10)?);
+        has_digits = true;
+        frac_digits = frac_digits.map(|f| f + 1);
+        // accumulating negative numbers as negative allows to parse MIN
+        let v = acc.checked_mul(10)?;
+        acc = if negative {
+            v.checked_sub(d)?
+        } else {
+            v.checked_add(d)?
+        };
+    }
+    has_digits.then_some((acc, frac_digits.unwrap_or(0)))
+}
+
+impl<T: Number, const P: u32> FromStr for FixedDec<T, P> {
+    type Err = ParseFixedDecError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s)
+    }
+}
+
+impl<T: Number, const P: u32> TryFrom<&str> for FixedDec<T, P> {
+    type Error = ParseFixedDecError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        parse(s)
+    }
+}
+
+impl<T: Number, const P: u32> TryFrom<String> for FixedDec<T, P> {
+    type Error = ParseFixedDecError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        parse(&s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn round_trip() {
+        for v in [0, 1, -1, 1234, -1234, 100, i32::MAX, i32::MIN] {
+            let f = FixedDec::<i32, 3>::new(v);
+            let s = f.to_string();
+            assert_eq!(s.parse::<FixedDec<i32, 3>>(), Ok(f));
+            assert_eq!(FixedDec::<i32, 3>::try_from(s.as_str()), Ok(f));
+            assert_eq!(FixedDec::<i32, 3>::try_from(s), Ok(f));
+        }
+        assert_eq!("12.5".parse::<FixedDec<u8, 1>>(), Ok(FixedDec::new(125)));
+        assert_eq!("-0.5".parse::<FixedDec<i8, 2>>(), Ok(FixedDec::new(-50)));
+        assert_eq!(".5".parse::<FixedDec<u8, 1>>(), Ok(FixedDec::new(5)));
+        assert_eq!("1.239".parse::<FixedDec<u32, 2>>(), Ok(FixedDec::new(123)));
+    }
+
+    #[test]
+    fn malformed() {
+        type F = FixedDec<i32, 2>;
+        assert_eq!(F::try_from(""), Err(ParseFixedDecError::Empty));
+        assert_eq!(F::try_from("-."), Err(ParseFixedDecError::Empty));
+        assert_eq!(
+            F::try_from("1.2.3"),
+            Err(ParseFixedDecError::InvalidDigit { position: 3 })
+        );
+        assert_eq!(
+            F::try_from("12a"),
+            Err(ParseFixedDecError::InvalidDigit { position: 2 })
+        );
+        assert_eq!(
+            F::try_from("--1"),
+            Err(ParseFixedDecError::InvalidDigit { position: 1 })
+        );
+        assert_eq!(
+            F::try_from("+1"),
+            Err(ParseFixedDecError::InvalidDigit { position: 0 })
+        );
+        assert_eq!(
+            F::try_from("1.2x"),
+            Err(ParseFixedDecError::InvalidDigit { position: 3 })
+        );
+        assert_eq!(F::try_from("21474837"), Err(ParseFixedDecError::Overflow));
+        assert_eq!(
+            FixedDec::<u32, 2>::try_from("-1"),
+            Err(ParseFixedDecError::InvalidDigit { position: 0 })
+        );
+        assert_eq!(
+            FixedDec::<u8, 2>::try_from("3"),
+            Err(ParseFixedDecError::Overflow)
+        );
+    }
+
+    #[test]
+    fn invalid_digit_position() {
+        type F = FixedDec<u32, 3>;
+        let err = F::try_from("12.3x5").unwrap_err();
+        assert_eq!(err, ParseFixedDecError::InvalidDigit { position: 4 });
+        assert_eq!(&"12.3x5"[4..5], "x");
+        assert_eq!(
+            err.to_string(),
+            "invalid digit found in decimal at position 4"
+        );
+
+        // the offset is in bytes and counts the sign
+        let err = FixedDec::<i32, 2>::try_from("-1é5").unwrap_err();
+        assert_eq!(err, ParseFixedDecError::InvalidDigit { position: 2 });
+        let err = parse_with_separator::<i32, 2>("é1·5x", '·').unwrap_err();
+        assert_eq!(err, ParseFixedDecError::InvalidDigit { position: 0 });
+        let err = parse_with_separator::<i32, 2>("1·5x", '·').unwrap_err();
+        assert_eq!(err, ParseFixedDecError::InvalidDigit { position: 4 });
+    }
+
+    #[test]
+    fn decimal() {
+        assert_eq!(parse_decimal("1.2300"), Some((12300, 4)));
+        assert_eq!(parse_decimal("1234"), Some((1234, 0)));
+        assert_eq!(parse_decimal("0"), Some((0, 0)));
+        assert_eq!(parse_decimal("-0.05"), Some((-5, 2)));
+        assert_eq!(parse_decimal("12."), Some((12, 0)));
+        assert_eq!(parse_decimal(".5"), Some((5, 1)));
+        assert_eq!(
+            parse_decimal("-170141183460469231731687303715884105.728"),
+            Some((i128::MIN, 3))
+        );
+        assert_eq!(
+            parse_decimal("170141183460469231731687303715884105.728"),
+            None
+        );
+        assert_eq!(parse_decimal(""), None);
+        assert_eq!(parse_decimal("-"), None);
+        assert_eq!(parse_decimal("."), None);
+        assert_eq!(parse_decimal("1.2.3"), None);
+        assert_eq!(parse_decimal("+1"), None);
+        assert_eq!(parse_decimal("1e3"), None);
+    }
+
+    #[test]
+    fn separator() {
+        type F = FixedDec<i32, 2>;
+        assert_eq!(
+            parse_with_separator::<i32, 2>("12,34", ','),
+            Ok(F::new(1234))
+        );
+        assert_eq!(parse_with_separator::<i32, 2>("-0,5", ','), Ok(F::new(-50)));
+        assert_eq!(parse_with_separator::<i32, 2>("12", ','), Ok(F::new(1200)));
+        assert_eq!(parse_with_separator::<i32, 2>("1·5", '·'), Ok(F::new(150)));
+        assert_eq!(
+            parse_with_separator::<i32, 2>("12.34", ','),
+            Err(ParseFixedDecError::InvalidDigit { position: 2 })
+        );
+        assert_eq!(
+            parse_with_separator::<i32, 2>("1,2,3", ','),
+            Err(ParseFixedDecError::InvalidDigit { position: 3 })
+        );
+        assert_eq!(
+            parse_with_separator::<i32, 2>("1212", '1'),
+            Err(ParseFixedDecError::InvalidDigit { position: 0 })
+        );
+        assert_eq!(
+            parse_with_separator::<i32, 2>("-1-5", '-'),
+            Err(ParseFixedDecError::InvalidDigit { position: 0 })
+        );
+    }
+}
@@ -0,0 +1,88 @@
+//! Random generation of FixedDec values, with the `rand` feature
+//!
+//! The values are generated by sampling the raw backing value, so the
+//! [`StandardUniform`] distribution covers the whole range of the type,
+//! and a range of FixedDec is sampled uniformly over the raw values in the range:
+//!
+//! ```
+//! use fixeddec::FixedDec;
+//! use rand::{Rng, SeedableRng, rngs::SmallRng};
+//!
+//! let mut rng = SmallRng::seed_from_u64(1);
+//! let low = FixedDec::<u32, 2>::new(100);
+//! let high = FixedDec::<u32, 2>::new(200);
+//! let x = rng.random_range(low..high);
+//! assert!(x >= low && x < high);
+//! ```
+use rand::Rng;
+use rand::distr::uniform::{Error, SampleBorrow, SampleUniform, UniformSampler};
+use rand::distr::{Distribution, StandardUniform};
+
+use crate::FixedDec;
+use crate::number::Number;
+
+impl<T: Number, const P: u32> Distribution<FixedDec<T, P>> for StandardUniform
+where
+    StandardUniform: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> FixedDec<T, P> {
+        FixedDec::new(self.sample(rng))
+    }
+}
+
+/// Uniform sampler of FixedDec in a range
+#[derive(Clone, Copy, Debug)]
+pub struct UniformFixedDec<T: Number + SampleUniform, const P: u32>(T::Sampler);
+
+impl<T: Number + SampleUniform, const P: u32> UniformSampler for UniformFixedDec<T, P> {
+    type X = FixedDec<T, P>;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        T::Sampler::new(low.borrow().0, high.borrow().0).map(Self)
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        T::Sampler::new_inclusive(low.borrow().0, high.borrow().0).map(Self)
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        FixedDec::new(self.0.sample(rng))
+    }
+}
+
+impl<T: Number + SampleUniform, const P: u32> SampleUniform for FixedDec<T, P> {
+    type Sampler = UniformFixedDec<T, P>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::SmallRng};
+
+    #[test]
+    fn random_in_range() {
+        let mut rng = SmallRng::seed_from_u64(0x1234);
+        let low = FixedDec::<i32, 2>::new(-150);
+        let high = FixedDec::<i32, 2>::new(250);
+        for _ in 0..1000 {
+            let x = rng.random_range(low..high);
+            assert!(x >= low && x < high);
+            let y = rng.random_range(low..=high);
+            assert!(y >= low && y <= high);
+        }
+
+        let single = FixedDec::<u8, 1>::new(42);
+        assert_eq!(rng.random_range(single..=single), single);
+        assert!(UniformFixedDec::new(high, low).is_err());
+
+        let _: FixedDec<u128, 38> = rng.random();
+    }
+}
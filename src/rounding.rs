@@ -0,0 +1,119 @@
+use crate::number::Number;
+
+/// Rounding strategy to apply when dropping digits
+///
+/// The modes are defined relative to the represented value, so for example `HalfUp`
+/// is rounding half away from zero, which is the usual financial rounding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round toward zero (truncation): 1.7 -> 1, -1.7 -> -1
+    TowardZero,
+    /// Round away from zero: 1.2 -> 2, -1.2 -> -2
+    AwayFromZero,
+    /// Round toward negative infinity: 1.7 -> 1, -1.2 -> -2
+    Floor,
+    /// Round toward positive infinity: 1.2 -> 2, -1.7 -> -1
+    Ceiling,
+    /// Round to nearest, with ties away from zero: 2.5 -> 3, -2.5 -> -3
+    HalfUp,
+    /// Round to nearest, with ties toward zero: 2.5 -> 2, -2.5 -> -2
+    HalfDown,
+    /// Round to nearest, with ties to the even neighbour (banker's rounding): 2.5 -> 2, 3.5 -> 4
+    HalfEven,
+}
+
+/// Divide `n` by `d`, rounding the quotient according to `mode`
+///
+/// Returns None if `d` is zero, if the result doesn't fit in T, or if `d` is
+/// the minimum of a signed type (as its magnitude is not representable)
+pub(crate) fn div_round<T: Number>(n: T, d: T, mode: RoundingMode) -> Option<T> {
+    let q = n.checked_div(d)?;
+    // |q * d| <= |n| so this cannot overflow
+    let r = n.checked_sub(q.checked_mul(d)?)?;
+    if r == T::ZERO {
+        return Some(q);
+    }
+
+    let negative = (n < T::ZERO) != (d < T::ZERO);
+    let r_abs = if r < T::ZERO {
+        T::ZERO.checked_sub(r)?
+    } else {
+        r
+    };
+    let d_abs = if d < T::ZERO {
+        T::ZERO.checked_sub(d)?
+    } else {
+        d
+    };
+    // distance between the remainder and the next multiple of d
+    let other = d_abs - r_abs;
+
+    let away = match mode {
+        RoundingMode::TowardZero => false,
+        RoundingMode::AwayFromZero => true,
+        RoundingMode::Floor => negative,
+        RoundingMode::Ceiling => !negative,
+        RoundingMode::HalfUp => r_abs >= other,
+        RoundingMode::HalfDown => r_abs > other,
+        RoundingMode::HalfEven => {
+            r_abs > other || (r_abs == other && q.checked_rem(T::ONE + T::ONE) != Some(T::ZERO))
+        }
+    };
+
+    if !away {
+        Some(q)
+    } else if negative {
+        q.checked_sub(T::ONE)
+    } else {
+        q.checked_add(T::ONE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn div_round_modes() {
+        use RoundingMode::*;
+        let modes = [
+            TowardZero,
+            AwayFromZero,
+            Floor,
+            Ceiling,
+            HalfUp,
+            HalfDown,
+            HalfEven,
+        ];
+        // (numerator, [expected result of n / 10 for each mode])
+        let cases: [(i32, [i32; 7]); 10] = [
+            (17, [1, 2, 1, 2, 2, 2, 2]),
+            (12, [1, 2, 1, 2, 1, 1, 1]),
+            (15, [1, 2, 1, 2, 2, 1, 2]),
+            (25, [2, 3, 2, 3, 3, 2, 2]),
+            (20, [2, 2, 2, 2, 2, 2, 2]),
+            (-17, [-1, -2, -2, -1, -2, -2, -2]),
+            (-12, [-1, -2, -2, -1, -1, -1, -1]),
+            (-15, [-1, -2, -2, -1, -2, -1, -2]),
+            (-25, [-2, -3, -3, -2, -3, -2, -2]),
+            (0, [0, 0, 0, 0, 0, 0, 0]),
+        ];
+        for (n, expected) in cases {
+            for (mode, e) in modes.iter().zip(expected) {
+                assert_eq!(div_round(n, 10, *mode), Some(e), "{} / 10 {:?}", n, mode);
+                assert_eq!(
+                    div_round(-n, -10, *mode),
+                    Some(e),
+                    "{} / -10 {:?}",
+                    -n,
+                    mode
+                );
+            }
+        }
+
+        assert_eq!(div_round(15u8, 10, HalfEven), Some(2));
+        assert_eq!(div_round(255u8, 2, HalfUp), Some(128));
+        assert_eq!(div_round(5u8, 0, HalfUp), None);
+        assert_eq!(div_round(i8::MIN, -1, HalfUp), None);
+    }
+}
@@ -0,0 +1,186 @@
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
+
+use crate::FixedDec;
+use crate::number::Number;
+
+/// A FixedDec whose operators always check for overflow
+///
+/// The operators of FixedDec follow the builtin integer semantics, and silently wrap
+/// on overflow in release builds. The operators of this wrapper are computed with the
+/// checked methods of FixedDec, and panic on overflow in all the build profiles, so that
+/// an overflow can never produce a wrong value.
+///
+/// ```
+/// use fixeddec::{FixedDec, StrictFixedDec};
+/// let a = StrictFixedDec::new(FixedDec::<u32, 2>::new(150));
+/// let b = StrictFixedDec::new(FixedDec::<u32, 2>::new(25));
+/// assert_eq!((a + b * 2).get(), FixedDec::new(200));
+/// ```
+///
+/// ```should_panic
+/// use fixeddec::{FixedDec, StrictFixedDec};
+/// let a = StrictFixedDec::new(FixedDec::<u32, 2>::MAX);
+/// let _ = a + StrictFixedDec::new(FixedDec::new(1)); // panics even in release builds
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct StrictFixedDec<T: Number, const P: u32>(FixedDec<T, P>);
+
+impl<T: Number, const P: u32> StrictFixedDec<T, P> {
+    /// Create a StrictFixedDec from a FixedDec value
+    pub const fn new(value: FixedDec<T, P>) -> Self {
+        Self(value)
+    }
+
+    /// Return the underlying FixedDec value
+    pub const fn get(self) -> FixedDec<T, P> {
+        self.0
+    }
+}
+
+impl<T: Number, const P: u32> From<FixedDec<T, P>> for StrictFixedDec<T, P> {
+    fn from(value: FixedDec<T, P>) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: Number, const P: u32> From<StrictFixedDec<T, P>> for FixedDec<T, P> {
+    fn from(value: StrictFixedDec<T, P>) -> Self {
+        value.0
+    }
+}
+
+impl<T: Number, const P: u32> fmt::Display for StrictFixedDec<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+// implement an operator and its assign variant with a checked method of FixedDec
+macro_rules! strict_binop {
+    ($imp:ident::$method:ident, $assign_imp:ident::$assign_method:ident, $rhs:ty, $checked:ident, $msg:expr) => {
+        impl<T: Number, const P: u32> $imp<$rhs> for StrictFixedDec<T, P> {
+            type Output = Self;
+
+            fn $method(self, rhs: $rhs) -> Self::Output {
+                Self(self.0.$checked(rhs.into()).expect($msg))
+            }
+        }
+
+        impl<T: Number, const P: u32> $assign_imp<$rhs> for StrictFixedDec<T, P> {
+            fn $assign_method(&mut self, rhs: $rhs) {
+                *self = $imp::$method(*self, rhs);
+            }
+        }
+    };
+}
+
+strict_binop!(
+    Add::add,
+    AddAssign::add_assign,
+    Self,
+    checked_add,
+    "attempt to add with overflow"
+);
+strict_binop!(
+    Sub::sub,
+    SubAssign::sub_assign,
+    Self,
+    checked_sub,
+    "attempt to subtract with overflow"
+);
+strict_binop!(
+    Mul::mul,
+    MulAssign::mul_assign,
+    T,
+    checked_mul,
+    "attempt to multiply with overflow"
+);
+strict_binop!(
+    Div::div,
+    DivAssign::div_assign,
+    T,
+    checked_div,
+    "attempt to divide by zero or with overflow"
+);
+strict_binop!(
+    Rem::rem,
+    RemAssign::rem_assign,
+    T,
+    checked_rem,
+    "attempt to calculate the remainder with a divisor of zero or overflow"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operators() {
+        let a = StrictFixedDec::new(FixedDec::<i32, 2>::new(1000));
+        let b = StrictFixedDec::new(FixedDec::<i32, 2>::new(-250));
+
+        assert_eq!((a + b).get(), FixedDec::new(750));
+        assert_eq!((a - b).get(), FixedDec::new(1250));
+        assert_eq!((b * 3).get(), FixedDec::new(-750));
+        assert_eq!((a / 3).get(), FixedDec::new(333));
+        assert_eq!((a % 3).get(), FixedDec::new(1));
+
+        let mut c = a;
+        c += b;
+        c -= b;
+        c *= 2;
+        c /= 4;
+        c %= 400;
+        assert_eq!(FixedDec::from(c), FixedDec::new(100));
+        assert_eq!(alloc::format!("{:>6}", c), "  1.00");
+    }
+
+    #[test]
+    fn in_range() {
+        let max = StrictFixedDec::new(FixedDec::<u8, 1>::MAX);
+        let one = StrictFixedDec::new(FixedDec::<u8, 1>::new(1));
+        assert_eq!((max - one + one).get(), FixedDec::MAX);
+        assert_eq!((max / 5 * 5).get(), FixedDec::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn add_overflow() {
+        let max = StrictFixedDec::new(FixedDec::<u8, 1>::MAX);
+        let _ = max + StrictFixedDec::new(FixedDec::new(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn add_assign_overflow() {
+        let mut max = StrictFixedDec::new(FixedDec::<i64, 4>::MAX);
+        max += StrictFixedDec::new(FixedDec::new(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn sub_overflow() {
+        let zero = StrictFixedDec::new(FixedDec::<u32, 2>::new(0));
+        let _ = zero - StrictFixedDec::new(FixedDec::new(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to multiply with overflow")]
+    fn mul_overflow() {
+        let _ = StrictFixedDec::new(FixedDec::<i8, 1>::MIN) * -1;
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero or with overflow")]
+    fn div_overflow() {
+        let _ = StrictFixedDec::new(FixedDec::<i16, 1>::MIN) / -1;
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to calculate the remainder with a divisor of zero")]
+    fn rem_by_zero() {
+        let _ = StrictFixedDec::new(FixedDec::<u16, 1>::MAX) % 0;
+    }
+}